@@ -42,8 +42,33 @@ impl<'a> InvalidUuid<'a> {
     /// Converts the lightweight error type into detailed diagnostics.
     pub fn into_err(self) -> Error {
         let (s, offset, simple) = match self.0.as_bytes() {
-            [b'{', s @ .., b'}'] => (s, 1, false),
-            [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..] => {
+            [
+                b'{',
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+                b'}',
+            ] => (s, "{urn:uuid:".len(), false),
+            [b'{', s @ .., b'}'] | [b'(', s @ .., b')'] => (s, 1, false),
+            [
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+            ] => {
                 (s, "urn:uuid:".len(), false)
             }
             s => (s, 0, true),
@@ -70,7 +95,7 @@ impl<'a> InvalidUuid<'a> {
                     group_bounds[hyphen_count] = index;
                 }
                 hyphen_count += 1;
-            } else if !matches!(byte, b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F') {
+            } else if !byte.is_ascii_hexdigit() {
                 // Non-hex char
                 return Error(ErrorKind::Char {
                     character: byte as char,
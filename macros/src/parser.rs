@@ -12,19 +12,51 @@
 use crate::error::InvalidUuid;
 
 #[inline]
-pub const fn try_parse(input: &str) -> Result<[u8; 16], InvalidUuid> {
+pub const fn try_parse(input: &str) -> Result<[u8; 16], InvalidUuid<'_>> {
     let result = match (input.len(), input.as_bytes()) {
         // Inputs of 32 bytes must be a non-hyphenated UUID
         (32, s) => parse_simple(s),
         // Hyphenated UUIDs may be wrapped in various ways:
         // - `{UUID}` for braced UUIDs
-        // - `urn:uuid:UUID` for URNs
+        // - `(UUID)` for parenthesized UUIDs, like the .NET `P` format
+        // - `urn:uuid:UUID` for URNs (the prefix is case-insensitive)
+        // - `{urn:uuid:UUID}` for braced URNs
         // - `UUID` for a regular hyphenated UUID
         (36, s)
         | (38, [b'{', s @ .., b'}'])
-        | (45, [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..]) => {
-            parse_hyphenated(s)
-        }
+        | (38, [b'(', s @ .., b')'])
+        | (
+            45,
+            [
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+            ],
+        )
+        | (
+            47,
+            [
+                b'{',
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+                b'}',
+            ],
+        ) => parse_hyphenated(s),
         // Any other shaped input is immediately invalid
         _ => Err(()),
     };
@@ -70,7 +70,7 @@ impl Uuid {
     /// );
     /// ```
     pub const fn nil() -> Self {
-        Uuid::from_bytes([0; 16])
+        Uuid::from_bytes([0; Uuid::BYTES_LEN])
     }
 
     /// The 'max UUID' (all ones).
@@ -97,7 +97,7 @@ impl Uuid {
     /// ```
     #[cfg(uuid_unstable)]
     pub const fn max() -> Self {
-        Uuid::from_bytes([0xFF; 16])
+        Uuid::from_bytes([0xFF; Uuid::BYTES_LEN])
     }
 
     /// Creates a UUID from four field values.
@@ -141,6 +141,39 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from four field values, taking the last field by value.
+    ///
+    /// This is the same as [`Uuid::from_fields`], but doesn't need a reference
+    /// to the last field, which can be more convenient when building UUIDs in
+    /// `const` tables. The bytes of `d4` are used in the order given, so
+    /// they're big-endian like the other fields.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// const UUIDS: [Uuid; 2] = [
+    ///     Uuid::from_fields_owned(
+    ///         0xa1a2a3a4,
+    ///         0xb1b2,
+    ///         0xc1c2,
+    ///         [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8],
+    ///     ),
+    ///     Uuid::from_fields_owned(0, 0, 0, [0; 8]),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     UUIDS[0].hyphenated().to_string(),
+    /// );
+    /// assert!(UUIDS[1].is_nil());
+    /// ```
+    pub const fn from_fields_owned(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Uuid {
+        Uuid::from_fields(d1, d2, d3, &d4)
+    }
+
     /// Creates a UUID from four field values in little-endian order.
     ///
     /// The bytes in the `d1`, `d2` and `d3` fields will be flipped to convert
@@ -187,6 +220,49 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from four field values, where the last field is a
+    /// slice.
+    ///
+    /// This is the same as [`Uuid::from_fields`], but for when the length of
+    /// the last field is only known at runtime.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `d4` has any length other than 8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let d1 = 0xa1a2a3a4;
+    /// let d2 = 0xb1b2;
+    /// let d3 = 0xc1c2;
+    /// let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
+    ///
+    /// let uuid = Uuid::from_fields_slice(d1, d2, d3, &d4[..])?;
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    ///
+    /// assert!(Uuid::from_fields_slice(d1, d2, d3, &d4[..7]).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fields_slice(d1: u32, d2: u16, d3: u16, d4: &[u8]) -> Result<Uuid, Error> {
+        if d4.len() != 8 {
+            return Err(Error(ErrorKind::FieldLength { len: d4.len() }));
+        }
+
+        let mut node = [0; 8];
+        node.copy_from_slice(d4);
+        Ok(Uuid::from_fields(d1, d2, d3, &node))
+    }
+
     /// Creates a UUID from a 128bit value.
     ///
     /// # Examples
@@ -307,6 +383,107 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from the two `UInt64` fields of a Cap'n Proto struct.
+    ///
+    /// `hi` holds the 64 most significant bits of the UUID and `lo` the 64
+    /// least significant, both as big-endian integers. This is the same as
+    /// [`Uuid::from_u64_pair`], and the inverse of [`Uuid::to_capnp`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::from_capnp(0xa1a2a3a4b1b2c1c2, 0xd1d2d3d4d5d6d7d8);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_capnp(hi: u64, lo: u64) -> Self {
+        Uuid::from_u64_pair(hi, lo)
+    }
+
+    /// Creates a UUID from the 16 bytes of a ULID.
+    ///
+    /// A ULID is a 128-bit integer encoded in big-endian order, with a 48-bit
+    /// Unix timestamp in milliseconds followed by 80 random bits. The bytes
+    /// are used as they are, so [`Uuid::as_bytes`] returns the ULID bytes
+    /// back without loss. The result usually won't have a valid UUID version
+    /// or variant.
+    ///
+    /// Also see [`Uuid::from_ulid_str`] for parsing the string form of a
+    /// ULID.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes = [
+    ///     0x01, 0x56, 0x3e, 0x3a, 0xb5, 0xd3, 0xd6, 0x76, 0x4c, 0x61, 0xef, 0xb9, 0x93, 0x02, 0xbd,
+    ///     0x5b,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_ulid_bytes(bytes);
+    ///
+    /// assert_eq!(
+    ///     "01563e3a-b5d3-d676-4c61-efb99302bd5b",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// assert_eq!(&bytes, uuid.as_bytes());
+    /// ```
+    pub const fn from_ulid_bytes(bytes: Bytes) -> Self {
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Creates a UUID from a 32bit value and an array of 12 octets.
+    ///
+    /// This is the inverse of [`Uuid::split_high32`]. `hi` becomes the 32
+    /// most significant bits of the UUID, followed by the bytes of `rest`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let hi = 0xa1a2a3a4u32;
+    /// let rest = [
+    ///     0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    ///
+    /// let uuid = Uuid::join_high32(hi, rest);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn join_high32(hi: u32, rest: [u8; 12]) -> Self {
+        Uuid::from_bytes([
+            (hi >> 24) as u8,
+            (hi >> 16) as u8,
+            (hi >> 8) as u8,
+            hi as u8,
+            rest[0],
+            rest[1],
+            rest[2],
+            rest[3],
+            rest[4],
+            rest[5],
+            rest[6],
+            rest[7],
+            rest[8],
+            rest[9],
+            rest[10],
+            rest[11],
+        ])
+    }
+
     /// Creates a UUID using the supplied bytes.
     ///
     /// # Errors
@@ -337,11 +514,11 @@ impl Uuid {
     /// # }
     /// ```
     pub fn from_slice(b: &[u8]) -> Result<Uuid, Error> {
-        if b.len() != 16 {
+        if b.len() != Uuid::BYTES_LEN {
             return Err(Error(ErrorKind::ByteLength { len: b.len() }));
         }
 
-        let mut bytes: Bytes = [0; 16];
+        let mut bytes: Bytes = [0; Uuid::BYTES_LEN];
         bytes.copy_from_slice(b);
         Ok(Uuid::from_bytes(bytes))
     }
@@ -378,17 +555,21 @@ impl Uuid {
     /// # }
     /// ```
     pub fn from_slice_le(b: &[u8]) -> Result<Uuid, Error> {
-        if b.len() != 16 {
+        if b.len() != Uuid::BYTES_LEN {
             return Err(Error(ErrorKind::ByteLength { len: b.len() }));
         }
 
-        let mut bytes: Bytes = [0; 16];
+        let mut bytes: Bytes = [0; Uuid::BYTES_LEN];
         bytes.copy_from_slice(b);
         Ok(Uuid::from_bytes_le(bytes))
     }
 
     /// Creates a UUID using the supplied bytes.
     ///
+    /// Any 16 bytes are a valid `Uuid`, so this method doesn't perform any
+    /// validation and there's no need for an unchecked variant. To reinterpret
+    /// a borrowed buffer without copying it, use [`Uuid::from_bytes_ref`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -416,6 +597,34 @@ impl Uuid {
         Uuid(bytes)
     }
 
+    /// Creates a UUID using any value that can be converted into [`Bytes`].
+    ///
+    /// This is a generic alternative to [`Uuid::from_bytes`] for newtypes that
+    /// wrap a `[u8; 16]` and implement `Into` for it. Unlike `from_bytes`, it
+    /// can't be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// struct RawId([u8; 16]);
+    ///
+    /// impl From<RawId> for [u8; 16] {
+    ///     fn from(id: RawId) -> Self {
+    ///         id.0
+    ///     }
+    /// }
+    ///
+    /// let uuid = Uuid::from_bytes_into(RawId([0xa1; 16]));
+    ///
+    /// assert_eq!(Uuid::from_bytes([0xa1; 16]), uuid);
+    /// ```
+    pub fn from_bytes_into<B: Into<Bytes>>(bytes: B) -> Uuid {
+        Uuid::from_bytes(bytes.into())
+    }
+
     /// Creates a UUID using the supplied bytes in little endian order.
     ///
     /// The individual fields encoded in the buffer will be flipped.
@@ -450,8 +659,59 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID using 32 nibbles (4-bit values) in big-endian order.
+    ///
+    /// Each pair of nibbles is packed into one byte, with the first nibble
+    /// of the pair in the high bits. Every nibble is expected to be less
+    /// than 16; in debug builds a larger value will panic, otherwise its
+    /// high bits are discarded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let nibbles = [
+    ///     0xa, 0x1, 0xa, 0x2, 0xa, 0x3, 0xa, 0x4,
+    ///     0xb, 0x1, 0xb, 0x2,
+    ///     0xc, 0x1, 0xc, 0x2,
+    ///     0xd, 0x1, 0xd, 0x2, 0xd, 0x3, 0xd, 0x4, 0xd, 0x5, 0xd, 0x6, 0xd, 0x7, 0xd, 0x8,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_nibbles(&nibbles);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn from_nibbles(nibbles: &[u8; 32]) -> Uuid {
+        let mut bytes = [0; Uuid::BYTES_LEN];
+
+        let mut i = 0;
+        while i < Uuid::BYTES_LEN {
+            let hi = nibbles[i * 2];
+            let lo = nibbles[i * 2 + 1];
+
+            debug_assert!(hi < 16 && lo < 16, "nibbles must be less than 16");
+
+            bytes[i] = (hi << 4) | (lo & 0x0f);
+            i += 1;
+        }
+
+        Uuid::from_bytes(bytes)
+    }
+
     /// Creates a reference to a UUID from a reference to the supplied bytes.
     ///
+    /// This is a zero-cost cast that borrows the original buffer, so it can be
+    /// used to view bytes handed over through FFI as a `Uuid` without copying
+    /// them. It's always safe because [`Bytes`] and `Uuid` share the same ABI.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -485,6 +745,33 @@ impl Uuid {
         unsafe { &*(bytes as *const Bytes as *const Uuid) }
     }
 
+    /// Creates a UUID from the 16 octets of an IPv6 address.
+    ///
+    /// The octets are used as-is, so this is the inverse of
+    /// [`Uuid::to_ipv6`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::net::Ipv6Addr;
+    /// # use uuid::Uuid;
+    /// let addr = Ipv6Addr::new(0xa1a2, 0xa3a4, 0xb1b2, 0xc1c2, 0xd1d2, 0xd3d4, 0xd5d6, 0xd7d8);
+    ///
+    /// let uuid = Uuid::from_ipv6(addr);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_ipv6(addr: std::net::Ipv6Addr) -> Uuid {
+        Uuid::from_bytes(addr.octets())
+    }
+
     // NOTE: There is no `from_u128_ref` because in little-endian
     // environments the value isn't properly encoded. Callers would
     // need to use `.to_be()` themselves.
@@ -1,6 +1,15 @@
-use crate::std::fmt;
+use crate::{
+    parser::{is_supported_len, SUPPORTED_LENGTHS},
+    std::fmt,
+};
 
 /// A general error that can occur when working with UUIDs.
+///
+/// The `Display` implementation describes what was wrong with the input,
+/// including the position of an invalid character or group, so the message
+/// is useful on its own when the error is wrapped in another one. With the
+/// `std` feature this type implements `std::error::Error`. None of these
+/// errors are caused by another error, so `source` always returns `None`.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Error(pub(crate) ErrorKind);
 
@@ -14,8 +23,16 @@ pub(crate) enum ErrorKind {
     ///
     /// [`Uuid`]: ../struct.Uuid.html
     SimpleLength { len: usize },
+    /// A [`Uuid`] string wasn't the length of any supported format.
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    InputLength { len: usize },
     /// A byte array didn't contain 16 bytes
     ByteLength { len: usize },
+    /// The last field of a [`Uuid`] didn't contain 8 bytes
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    FieldLength { len: usize },
     /// A hyphenated [`Uuid`] didn't contain 5 groups
     ///
     /// [`Uuid`]: ../struct.Uuid.html
@@ -30,10 +47,104 @@ pub(crate) enum ErrorKind {
     },
     /// The input was not a valid UTF8 string
     InvalidUTF8,
+    /// The input didn't end with a `:` followed by a 2 digit checksum
+    MissingChecksum,
+    /// The checksum of the input didn't match its [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ChecksumMismatch { expected: u8, found: u8 },
+    /// A shortuuid didn't contain 22 characters
+    ShortUuidLength { len: usize },
+    /// A shortuuid encoded a value too large for a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ShortUuidOverflow,
+    /// A ULID didn't contain 26 characters
+    UlidLength { len: usize },
+    /// A ULID encoded a value too large for a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    UlidOverflow,
+    /// A decimal string didn't contain any digits
+    DecimalEmpty,
+    /// A decimal string encoded a value too large for a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    DecimalOverflow,
+    /// A .NET format specifier wasn't one of `N`, `D`, `B`, or `P`
+    #[cfg(feature = "std")]
+    DotnetFormat { format: char },
+    /// A timestamp didn't fit in the 48 bit field of a version 7 [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    TimestampOverflow,
+    /// A [`Uuid`] was nil where a non-nil one was expected
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    Nil,
+    /// The operating system's random number generator failed
+    #[cfg(all(feature = "v4", not(feature = "fast-rng")))]
+    Random { code: crate::std::num::NonZeroU32 },
+    /// Some other error occurred.
+    Other,
+}
+
+/// A lightweight description of why a string failed to parse as a [`Uuid`].
+///
+/// Unlike [`Error`], this type doesn't carry the position or contents of
+/// the invalid input, so it's `Copy`. It's returned by
+/// [`Uuid::parse_str_light`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+/// [`Error`]: struct.Error.html
+/// [`Uuid::parse_str_light`]: ../struct.Uuid.html#method.parse_str_light
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input contained a character that isn't valid in a UUID.
+    Char,
+    /// The input wasn't the length of any supported format.
+    Length,
+    /// A hyphenated UUID didn't contain 5 groups.
+    GroupCount,
+    /// A hyphenated UUID had a group that wasn't the right length.
+    GroupLength,
     /// Some other error occurred.
     Other,
 }
 
+impl ErrorKind {
+    pub(crate) const fn parse_error_kind(&self) -> ParseErrorKind {
+        match self {
+            ErrorKind::Char { .. } => ParseErrorKind::Char,
+            ErrorKind::SimpleLength { .. }
+            | ErrorKind::InputLength { .. }
+            | ErrorKind::ByteLength { .. }
+            | ErrorKind::FieldLength { .. }
+            | ErrorKind::ShortUuidLength { .. }
+            | ErrorKind::UlidLength { .. }
+            | ErrorKind::DecimalEmpty => ParseErrorKind::Length,
+            ErrorKind::GroupCount { .. } => ParseErrorKind::GroupCount,
+            ErrorKind::GroupLength { .. } => ParseErrorKind::GroupLength,
+            ErrorKind::InvalidUTF8
+            | ErrorKind::MissingChecksum
+            | ErrorKind::ChecksumMismatch { .. }
+            | ErrorKind::ShortUuidOverflow
+            | ErrorKind::UlidOverflow
+            | ErrorKind::DecimalOverflow
+            | ErrorKind::Nil
+            | ErrorKind::Other => ParseErrorKind::Other,
+            #[cfg(feature = "std")]
+            ErrorKind::DotnetFormat { .. } => ParseErrorKind::Other,
+            #[cfg(all(uuid_unstable, feature = "v7"))]
+            ErrorKind::TimestampOverflow => ParseErrorKind::Other,
+            #[cfg(all(feature = "v4", not(feature = "fast-rng")))]
+            ErrorKind::Random { .. } => ParseErrorKind::Other,
+        }
+    }
+}
+
 /// A string that is guaranteed to fail to parse to a [`Uuid`].
 ///
 /// This type acts as a lightweight error indicator, suggesting
@@ -53,9 +164,54 @@ impl<'a> InvalidUuid<'a> {
             Err(_) => return Error(ErrorKind::InvalidUTF8),
         };
 
+        // Invisible characters like a byte order mark are easy to pick up
+        // when copying from files or documents, so report them directly
+        // before anything else obscures them
+        if let Some((index, character)) = input_str.char_indices().find(|&(_, c)| is_invisible(c)) {
+            return Error(ErrorKind::Char {
+                character,
+                index: index + 1,
+            });
+        }
+
+        // Inputs that can't be any supported format are rejected without
+        // walking their characters or groups to find a more specific error.
+        // Invisible characters are still reported first, since they're the
+        // likely reason the length is wrong
+        if !is_supported_len(input_str.len()) {
+            return Error(ErrorKind::InputLength {
+                len: input_str.len(),
+            });
+        }
+
         let (uuid_str, offset, simple) = match input_str.as_bytes() {
-            [b'{', s @ .., b'}'] => (s, 1, false),
-            [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..] => {
+            [
+                b'{',
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+                b'}',
+            ] => (s, "{urn:uuid:".len(), false),
+            [b'{', s @ .., b'}'] | [b'(', s @ .., b')'] => (s, 1, false),
+            [
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+            ] => {
                 (s, "urn:uuid:".len(), false)
             }
             s => (s, 0, true),
@@ -82,7 +238,7 @@ impl<'a> InvalidUuid<'a> {
                     group_bounds[hyphen_count] = index;
                 }
                 hyphen_count += 1;
-            } else if !matches!(byte, b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F') {
+            } else if !byte.is_ascii_hexdigit() {
                 // Non-hex char
                 return Error(ErrorKind::Char {
                     character: byte as char,
@@ -120,17 +276,31 @@ impl<'a> InvalidUuid<'a> {
             // The last group must be too long
             Error(ErrorKind::GroupLength {
                 group: 4,
-                len: input_str.len() - BLOCK_STARTS[4],
+                len: uuid_str.len() - BLOCK_STARTS[4],
                 index: offset + BLOCK_STARTS[4] + 1,
             })
         }
     }
 }
 
+/// Whether a character renders as nothing, like a byte order mark or
+/// a zero-width space.
+const fn is_invisible(character: char) -> bool {
+    matches!(
+        character,
+        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+    )
+}
+
 // NOTE: This impl is part of the public API. Breaking changes to it should be carefully considered
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.0 {
+            ErrorKind::Char {
+                character, index, ..
+            } if is_invisible(character) => {
+                write!(f, "invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found invisible character `U+{:04X}` at {}", character as u32, index)
+            }
             ErrorKind::Char {
                 character, index, ..
             } => {
@@ -143,30 +313,116 @@ impl fmt::Display for Error {
                     len
                 )
             }
+            ErrorKind::InputLength { len } => {
+                f.write_str("invalid length: expected length ")?;
+                for (i, expected) in SUPPORTED_LENGTHS.iter().enumerate() {
+                    match i {
+                        0 => {}
+                        _ if i == SUPPORTED_LENGTHS.len() - 1 => f.write_str(", or ")?,
+                        _ => f.write_str(", ")?,
+                    }
+                    write!(f, "{}", expected)?;
+                }
+                write!(f, ", found {}", len)
+            }
             ErrorKind::ByteLength { len } => {
                 write!(f, "invalid length: expected 16 bytes, found {}", len)
             }
+            ErrorKind::FieldLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 8 bytes for the last field, found {}",
+                    len
+                )
+            }
             ErrorKind::GroupCount { count } => {
                 write!(f, "invalid group count: expected 5, found {}", count)
             }
-            ErrorKind::GroupLength { group, len, .. } => {
+            ErrorKind::GroupLength { group, len, index } => {
                 let expected = [8, 4, 4, 4, 12][group];
                 write!(
                     f,
-                    "invalid group length in group {}: expected {}, found {}",
-                    group, expected, len
+                    "invalid group length in group {} at {}: expected {}, found {}",
+                    group, index, expected, len
                 )
             }
             ErrorKind::InvalidUTF8 => write!(f, "non-UTF8 input"),
+            ErrorKind::MissingChecksum => write!(
+                f,
+                "missing checksum: expected `:` followed by 2 hex digits at the end of the input"
+            ),
+            ErrorKind::ChecksumMismatch { expected, found } => write!(
+                f,
+                "checksum mismatch: expected {:02X}, found {:02X}",
+                expected, found
+            ),
+            ErrorKind::ShortUuidLength { len } => write!(
+                f,
+                "invalid length: expected length 22 for shortuuid format, found {}",
+                len
+            ),
+            ErrorKind::ShortUuidOverflow => {
+                write!(
+                    f,
+                    "invalid shortuuid: the encoded value is too large for a UUID"
+                )
+            }
+            ErrorKind::UlidLength { len } => write!(
+                f,
+                "invalid length: expected length 26 for ULID format, found {}",
+                len
+            ),
+            ErrorKind::UlidOverflow => {
+                write!(f, "invalid ULID: the encoded value is too large for a UUID")
+            }
+            ErrorKind::DecimalEmpty => write!(
+                f,
+                "invalid length: expected at least 1 decimal digit, found 0"
+            ),
+            ErrorKind::DecimalOverflow => {
+                write!(f, "invalid decimal: the value is too large for a UUID")
+            }
+            #[cfg(feature = "std")]
+            ErrorKind::DotnetFormat { format } => write!(
+                f,
+                "invalid format: expected one of `N`, `D`, `B`, or `P`, found `{}`",
+                format
+            ),
+            #[cfg(all(uuid_unstable, feature = "v7"))]
+            ErrorKind::TimestampOverflow => write!(
+                f,
+                "invalid timestamp: expected at most 2^48 - 1 milliseconds since the Unix epoch"
+            ),
+            #[cfg(all(feature = "v4", not(feature = "fast-rng")))]
+            ErrorKind::Random { code } => write!(
+                f,
+                "could not retrieve random bytes for uuid: {}",
+                getrandom::Error::from(code)
+            ),
+            ErrorKind::Nil => write!(f, "the UUID is nil"),
             ErrorKind::Other => write!(f, "failed to parse a UUID"),
         }
     }
 }
 
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::Char => write!(f, "invalid character"),
+            ParseErrorKind::Length => write!(f, "invalid length"),
+            ParseErrorKind::GroupCount => write!(f, "invalid group count"),
+            ParseErrorKind::GroupLength => write!(f, "invalid group length"),
+            ParseErrorKind::Other => write!(f, "failed to parse a UUID"),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 mod std_support {
     use super::*;
     use crate::std::error;
 
     impl error::Error for Error {}
+
+    impl error::Error for ParseErrorKind {}
 }
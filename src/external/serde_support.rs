@@ -11,7 +11,7 @@
 
 use crate::{
     error::*,
-    fmt::{Braced, Hyphenated, Simple, Urn},
+    fmt::{Braced, FormattedUuid, Hyphenated, Simple, Urn},
     std::fmt,
     Uuid,
 };
@@ -54,6 +54,12 @@ impl Serialize for Braced {
     }
 }
 
+impl Serialize for FormattedUuid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.encode_lower(&mut Uuid::encode_buffer()))
+    }
+}
+
 impl<'de> Deserialize<'de> for Uuid {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         fn de_error<E: de::Error>(e: Error) -> E {
@@ -127,6 +133,28 @@ impl<'de> Deserialize<'de> for Uuid {
     }
 }
 
+impl<'de> Deserialize<'de> for FormattedUuid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FormattedUuidVisitor;
+
+        impl<'vi> de::Visitor<'vi> for FormattedUuidVisitor {
+            type Value = FormattedUuid;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a UUID string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<FormattedUuid, E> {
+                value
+                    .parse::<FormattedUuid>()
+                    .map_err(|e| E::custom(format_args!("UUID parsing failed: {}", e)))
+            }
+        }
+
+        deserializer.deserialize_str(FormattedUuidVisitor)
+    }
+}
+
 pub mod compact {
     //! Serialize a [`Uuid`] as a `[u8; 16]`.
     //!
@@ -207,6 +235,197 @@ pub mod compact {
     }
 }
 
+pub mod compact_le {
+    //! Serialize a [`Uuid`] as a `[u8; 16]` in little-endian field order.
+    //!
+    //! The fields of the UUID are flipped the same way as
+    //! [`Uuid::to_bytes_le`], so the bytes match the in-memory layout of a
+    //! Microsoft `GUID` struct on little-endian targets.
+    //!
+    //! [`Uuid`]: ../../struct.Uuid.html
+    //! [`Uuid::to_bytes_le`]: ../../struct.Uuid.html#method.to_bytes_le
+
+    /// Serialize from a [`Uuid`] as a little-endian `[u8; 16]`
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&u.to_bytes_le(), serializer)
+    }
+
+    /// Deserialize a little-endian `[u8; 16]` as a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes: [u8; 16] = serde::Deserialize::deserialize(deserializer)?;
+
+        Ok(crate::Uuid::from_bytes_le(bytes))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_derive::*;
+        use serde_test::{self, Configure};
+
+        #[test]
+        fn test_serialize_compact_le() {
+            #[derive(Serialize, Debug, Deserialize, PartialEq)]
+            struct UuidContainer {
+                #[serde(with = "crate::serde::compact_le")]
+                u: crate::Uuid,
+            }
+
+            let uuid_bytes = b"F9168C5E-CEB2-4F";
+            let le_bytes = crate::Uuid::from_slice(uuid_bytes).unwrap().to_bytes_le();
+            let container = UuidContainer {
+                u: crate::Uuid::from_slice(uuid_bytes).unwrap(),
+            };
+
+            assert_eq!(&le_bytes[..4], b"619F");
+
+            serde_test::assert_tokens(
+                &container.compact(),
+                &[
+                    serde_test::Token::Struct {
+                        name: "UuidContainer",
+                        len: 1,
+                    },
+                    serde_test::Token::Str("u"),
+                    serde_test::Token::Tuple { len: 16 },
+                    serde_test::Token::U8(le_bytes[0]),
+                    serde_test::Token::U8(le_bytes[1]),
+                    serde_test::Token::U8(le_bytes[2]),
+                    serde_test::Token::U8(le_bytes[3]),
+                    serde_test::Token::U8(le_bytes[4]),
+                    serde_test::Token::U8(le_bytes[5]),
+                    serde_test::Token::U8(le_bytes[6]),
+                    serde_test::Token::U8(le_bytes[7]),
+                    serde_test::Token::U8(le_bytes[8]),
+                    serde_test::Token::U8(le_bytes[9]),
+                    serde_test::Token::U8(le_bytes[10]),
+                    serde_test::Token::U8(le_bytes[11]),
+                    serde_test::Token::U8(le_bytes[12]),
+                    serde_test::Token::U8(le_bytes[13]),
+                    serde_test::Token::U8(le_bytes[14]),
+                    serde_test::Token::U8(le_bytes[15]),
+                    serde_test::Token::TupleEnd,
+                    serde_test::Token::StructEnd,
+                ],
+            )
+        }
+    }
+}
+
+pub mod nil_as_null {
+    //! Serialize an [`Option<Uuid>`] where the nil UUID is equivalent to `None`.
+    //!
+    //! Both `None` and `Some` of the nil UUID serialize as a null value,
+    //! such as JSON `null`. When deserializing, both a null value and the
+    //! nil UUID become `None`.
+    //!
+    //! [`Option<Uuid>`]: ../../struct.Uuid.html
+
+    /// Serialize from an [`Option<Uuid>`], writing the nil UUID as null
+    ///
+    /// [`Option<Uuid>`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &Option<crate::Uuid>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match u {
+            Some(u) if !u.is_nil() => serializer.serialize_some(u),
+            _ => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an [`Option<Uuid>`], reading the nil UUID as `None`
+    ///
+    /// [`Option<Uuid>`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<crate::Uuid>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let u: Option<crate::Uuid> = serde::Deserialize::deserialize(deserializer)?;
+
+        Ok(u.filter(|u| !u.is_nil()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_derive::*;
+        use serde_test::{self, Configure};
+
+        #[derive(Serialize, Debug, Deserialize, PartialEq)]
+        struct UuidContainer {
+            #[serde(with = "crate::serde::nil_as_null")]
+            u: Option<crate::Uuid>,
+        }
+
+        #[test]
+        fn test_serialize_nil_as_null() {
+            let uuid_str = "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4";
+            let container = UuidContainer {
+                u: Some(crate::Uuid::parse_str(uuid_str).unwrap()),
+            };
+
+            serde_test::assert_tokens(
+                &container.readable(),
+                &[
+                    serde_test::Token::Struct {
+                        name: "UuidContainer",
+                        len: 1,
+                    },
+                    serde_test::Token::Str("u"),
+                    serde_test::Token::Some,
+                    serde_test::Token::Str(uuid_str),
+                    serde_test::Token::StructEnd,
+                ],
+            );
+
+            let null = [
+                serde_test::Token::Struct {
+                    name: "UuidContainer",
+                    len: 1,
+                },
+                serde_test::Token::Str("u"),
+                serde_test::Token::None,
+                serde_test::Token::StructEnd,
+            ];
+
+            serde_test::assert_tokens(&UuidContainer { u: None }.readable(), &null);
+            serde_test::assert_ser_tokens(
+                &UuidContainer {
+                    u: Some(crate::Uuid::nil()),
+                }
+                .readable(),
+                &null,
+            );
+        }
+
+        #[test]
+        fn test_deserialize_nil_as_null() {
+            serde_test::assert_de_tokens(
+                &UuidContainer { u: None }.readable(),
+                &[
+                    serde_test::Token::Struct {
+                        name: "UuidContainer",
+                        len: 1,
+                    },
+                    serde_test::Token::Str("u"),
+                    serde_test::Token::Some,
+                    serde_test::Token::Str("00000000-0000-0000-0000-000000000000"),
+                    serde_test::Token::StructEnd,
+                ],
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
     use super::*;
@@ -286,6 +505,50 @@ mod serde_tests {
         serde_test::assert_ser_tokens(&u.braced(), &[Token::Str(uuid_str)]);
     }
 
+    #[test]
+    fn test_formatted_uuid() {
+        use crate::fmt::Format;
+
+        for &(input, output, format) in &[
+            (
+                "f9168c5eceb24faab6bf329bf39fa1e4",
+                "f9168c5eceb24faab6bf329bf39fa1e4",
+                Format::Simple,
+            ),
+            (
+                "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+                "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+                Format::Hyphenated,
+            ),
+            (
+                "URN:UUID:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+                "urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+                Format::Urn,
+            ),
+            (
+                "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+                "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+                Format::Braced,
+            ),
+            (
+                "(f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)",
+                "(f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)",
+                Format::Parenthesized,
+            ),
+        ] {
+            let formatted = FormattedUuid::new(crate::tests::new(), format);
+
+            serde_test::assert_de_tokens(&formatted, &[Token::Str(input)]);
+            serde_test::assert_tokens(&formatted, &[Token::Str(output)]);
+            serde_test::assert_ser_tokens(&formatted.compact(), &[Token::Str(output)]);
+        }
+
+        serde_test::assert_de_tokens_error::<FormattedUuid>(
+            &[Token::Str("f9168c5e-ceb2")],
+            "UUID parsing failed: invalid length: expected length 32, 36, 38, 45, or 47, found 13",
+        );
+    }
+
     #[test]
     fn test_serialize_non_human_readable() {
         let uuid_bytes = b"F9168C5E-CEB2-4F";
@@ -302,7 +565,7 @@ mod serde_tests {
     fn test_de_failure() {
         serde_test::assert_de_tokens_error::<Readable<Uuid>>(
             &[Token::Str("hello_world")],
-            "UUID parsing failed: invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found `h` at 1",
+            "UUID parsing failed: invalid length: expected length 32, 36, 38, 45, or 47, found 11",
         );
 
         serde_test::assert_de_tokens_error::<Compact<Uuid>>(
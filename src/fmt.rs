@@ -12,14 +12,40 @@
 //! Adapters for alternative string formats.
 
 use crate::{
-    std::{borrow::Borrow, fmt, ptr, str},
-    Uuid, Variant,
+    std::{borrow::Borrow, fmt, hash, iter, ptr, str},
+    Uuid, Variant, Version,
 };
 
 impl std::fmt::Debug for Uuid {
+    /// Formats the UUID as a lower-case hyphenated string.
+    ///
+    /// The alternate flag (`{:#?}`) instead formats the version, variant, and
+    /// fields of the UUID as a struct, which can be useful when debugging
+    /// byte-level issues.
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::LowerHex::fmt(self, f)
+        if f.alternate() {
+            let (time_low, time_mid, time_hi_and_version, d4) = self.as_fields();
+            let clock_seq = (d4[0] as u16) << 8 | d4[1] as u16;
+            let node = d4[2..]
+                .iter()
+                .fold(0u64, |node, byte| node << 8 | *byte as u64);
+
+            f.debug_struct("Uuid")
+                .field("version", &self.get_version_num())
+                .field("variant", &self.get_variant())
+                .field("time_low", &format_args!("{:#010x}", time_low))
+                .field("time_mid", &format_args!("{:#06x}", time_mid))
+                .field(
+                    "time_hi_and_version",
+                    &format_args!("{:#06x}", time_hi_and_version),
+                )
+                .field("clock_seq", &format_args!("{:#06x}", clock_seq))
+                .field("node", &format_args!("{:#014x}", node))
+                .finish()
+        } else {
+            fmt::LowerHex::fmt(self, f)
+        }
     }
 }
 
@@ -40,6 +66,27 @@ impl fmt::Display for Variant {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Version::Nil => write!(f, "nil"),
+            Version::Mac => write!(f, "v1 (time-based)"),
+            Version::Dce => write!(f, "v2 (DCE security)"),
+            Version::Md5 => write!(f, "v3 (MD5 hash)"),
+            Version::Random => write!(f, "v4 (random)"),
+            Version::Sha1 => write!(f, "v5 (SHA-1 hash)"),
+            #[cfg(uuid_unstable)]
+            Version::SortMac => write!(f, "v6 (sortable time-based)"),
+            #[cfg(uuid_unstable)]
+            Version::SortRand => write!(f, "v7 (Unix time-based)"),
+            #[cfg(uuid_unstable)]
+            Version::Custom => write!(f, "v8 (custom)"),
+            #[cfg(uuid_unstable)]
+            Version::Max => write!(f, "max"),
+        }
+    }
+}
+
 impl fmt::LowerHex for Uuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::LowerHex::fmt(self.as_hyphenated(), f)
@@ -77,6 +124,139 @@ pub struct Urn(Uuid);
 #[repr(transparent)]
 pub struct Braced(Uuid);
 
+/// A string format that a [`Uuid`] can be parsed from.
+///
+/// This is returned by [`Uuid::parse_str_with_format`] to describe which
+/// format the input was in.
+///
+/// [`Uuid::parse_str_with_format`]: ../struct.Uuid.html#method.parse_str_with_format
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Format {
+    /// A simple string, like `67e5504410b1426f9247bb680e5fe0c8`.
+    Simple,
+    /// A hyphenated string, like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Hyphenated,
+    /// A URN string, like `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Urn,
+    /// A braced hyphenated string, like
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    Braced,
+    /// A parenthesized hyphenated string, like
+    /// `(67e55044-10b1-426f-9247-bb680e5fe0c8)`.
+    Parenthesized,
+}
+
+/// A representation of a [`Uuid`] that isn't necessarily hexadecimal.
+///
+/// This is passed to [`Uuid::parse`] to choose how its input is decoded,
+/// so the representation can come from configuration.
+///
+/// [`Uuid::parse`]: ../struct.Uuid.html#method.parse
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Repr {
+    /// Any hexadecimal format accepted by [`Uuid::parse_str`], like
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    ///
+    /// [`Uuid::parse_str`]: ../struct.Uuid.html#method.parse_str
+    Hex,
+    /// The decimal string of the 128 bit value, as accepted by
+    /// [`Uuid::from_decimal_str`].
+    ///
+    /// [`Uuid::from_decimal_str`]: ../struct.Uuid.html#method.from_decimal_str
+    Decimal,
+    /// The 22 character base57 string used by the Python `shortuuid`
+    /// library, as accepted by [`Uuid::from_shortuuid`].
+    ///
+    /// [`Uuid::from_shortuuid`]: ../struct.Uuid.html#method.from_shortuuid
+    ShortUuid,
+}
+
+/// A [`Uuid`] along with the [`Format`] of its string.
+///
+/// Parsing a `FormattedUuid` remembers whether the input was simple,
+/// hyphenated, a URN, braced, or parenthesized, and formatting it writes
+/// the `Uuid` back out the same way. With the `serde` feature this also
+/// applies to serialization, so round-tripping a config file doesn't
+/// normalize the UUIDs in it.
+///
+/// The hex digits are always written in lower-case, and a URN prefix is
+/// always written as `urn:uuid:`, regardless of the case of the input.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::{FormattedUuid, Format}, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let formatted: FormattedUuid = "{67E55044-10B1-426F-9247-BB680E5FE0C8}".parse()?;
+///
+/// assert_eq!(Format::Braced, formatted.format());
+/// assert_eq!("{67e55044-10b1-426f-9247-bb680e5fe0c8}", formatted.to_string());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FormattedUuid {
+    uuid: Uuid,
+    format: Format,
+}
+
+/// An owned lower-case simple string of a [`Uuid`], like
+/// `67e5504410b1426f9247bb680e5fe0c8`.
+///
+/// The string is stored inline rather than in a heap allocated `String`, so
+/// this type is `Copy`. It hashes and compares the same way as the `str` it
+/// contains, so it can be used as the key of a map that's looked up by
+/// `&str`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use uuid::{fmt::SimpleStr, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// let mut map = HashMap::<SimpleStr, u32>::new();
+/// map.insert(uuid.to_simple_str(), 42);
+///
+/// assert_eq!(Some(&42), map.get("67e5504410b1426f9247bb680e5fe0c8"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SimpleStr([u8; Simple::LENGTH]);
+
+/// An iterator over the lower-case characters of a formatted [`Uuid`].
+///
+/// This is returned by the `chars` method of each of the formatters, like
+/// [`Hyphenated::chars`]. The string is encoded into a buffer inside the
+/// iterator, so consuming it one character at a time doesn't need to
+/// allocate a `String`.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::Uuid;
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+///
+/// let mut chars = uuid.hyphenated().chars();
+///
+/// assert_eq!(Some('6'), chars.next());
+/// assert_eq!(Some('8'), chars.next_back());
+/// assert_eq!(34, chars.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chars {
+    buf: [u8; Urn::LENGTH],
+    front: usize,
+    back: usize,
+}
+
 impl Uuid {
     /// Get a [`Hyphenated`] formatter.
     #[inline]
@@ -104,6 +284,12 @@ impl Uuid {
         unsafe { &*(self as *const Uuid as *const Simple) }
     }
 
+    /// Get the lower-case simple string of the UUID as a [`SimpleStr`].
+    #[inline]
+    pub const fn to_simple_str(&self) -> SimpleStr {
+        SimpleStr(format_simple(self.as_bytes(), false))
+    }
+
     /// Get a [`Urn`] formatter.
     #[inline]
     pub const fn urn(self) -> Urn {
@@ -129,6 +315,637 @@ impl Uuid {
         // SAFETY: `Uuid` and `Braced` have the same ABI
         unsafe { &*(self as *const Uuid as *const Braced) }
     }
+
+    /// Get the lower-case hyphenated string of the UUID as an array of
+    /// ASCII bytes.
+    ///
+    /// The returned array is always valid UTF8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("936DA01F9ABD4d9d80C702AF85C822A8")?;
+    ///
+    /// let bytes = uuid.to_hyphenated_bytes();
+    ///
+    /// assert_eq!(&bytes, b"936da01f-9abd-4d9d-80c7-02af85c822a8");
+    /// assert_eq!(
+    ///     std::str::from_utf8(&bytes).unwrap(),
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// This method is `const`, so the string of a UUID that's known at
+    /// compile time can be formatted at compile time too:
+    ///
+    /// ```
+    /// # use uuid::{uuid, Uuid};
+    /// const ID: Uuid = uuid!("936DA01F-9ABD-4D9D-80C7-02AF85C822A8");
+    /// static ID_STR: [u8; 36] = ID.to_hyphenated_bytes();
+    ///
+    /// let id_str: &'static [u8; 36] = &ID_STR;
+    ///
+    /// assert_eq!(id_str, b"936da01f-9abd-4d9d-80c7-02af85c822a8");
+    /// ```
+    #[inline]
+    pub const fn to_hyphenated_bytes(&self) -> [u8; Hyphenated::LENGTH] {
+        format_hyphenated(self.as_bytes(), false)
+    }
+
+    /// Get the lower-case simple string of the UUID as an array of
+    /// ASCII bytes.
+    ///
+    /// The returned array is always valid UTF8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("936DA01F-9ABD-4D9D-80C7-02AF85C822A8")?;
+    ///
+    /// let bytes = uuid.to_simple_bytes();
+    ///
+    /// assert_eq!(&bytes, b"936da01f9abd4d9d80c702af85c822a8");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn to_simple_bytes(&self) -> [u8; Simple::LENGTH] {
+        format_simple(self.as_bytes(), false)
+    }
+
+    /// Get the upper-case simple string of the UUID as an array of
+    /// ASCII bytes.
+    ///
+    /// This is the same as [`Uuid::to_simple_bytes`], but with upper-case
+    /// hex digits. The returned array is always valid UTF8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?;
+    ///
+    /// let bytes = uuid.to_simple_bytes_upper();
+    ///
+    /// assert_eq!(&bytes, b"936DA01F9ABD4D9D80C702AF85C822A8");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn to_simple_bytes_upper(&self) -> [u8; Simple::LENGTH] {
+        format_simple(self.as_bytes(), true)
+    }
+
+    /// Get a formatter for the first group of the UUID only.
+    ///
+    /// This writes the `time_low` field as 8 lower-case hex digits, which
+    /// is a common shortened form of a UUID for logs. It isn't a unique
+    /// identifier on its own, so shouldn't be parsed or stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("936DA01F-9ABD-4D9D-80C7-02AF85C822A8")?;
+    ///
+    /// assert_eq!("936da01f", uuid.short().to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn short(&self) -> impl fmt::Display {
+        Short(self.as_fields().0)
+    }
+
+    /// Get a [RFC4151] `tag:` URI that uses this UUID as its specific part.
+    ///
+    /// The URI has the form `tag:{authority},{date}:{uuid}`, where the UUID
+    /// is formatted as a lower-case hyphenated string. The `authority` should
+    /// be a domain name or email address, and the `date` should be formatted
+    /// as `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`. Neither is validated.
+    ///
+    /// This is an alternative to the `urn:uuid:` URIs produced by [`Urn`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("936DA01F-9ABD-4D9D-80C7-02AF85C822A8")?;
+    ///
+    /// assert_eq!(
+    ///     "tag:example.com,2023-01:936da01f-9abd-4d9d-80c7-02af85c822a8",
+    ///     uuid.to_tag_uri("example.com", "2023-01"),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [RFC4151]: https://www.rfc-editor.org/rfc/rfc4151
+    #[cfg(feature = "std")]
+    pub fn to_tag_uri(&self, authority: &str, date: &str) -> std::string::String {
+        format!("tag:{},{}:{}", authority, date, self.hyphenated())
+    }
+
+    /// Get the lower-case hyphenated string of the UUID followed by a `:` and
+    /// a checksum of its bytes.
+    ///
+    /// The checksum is written as 2 upper-case hex digits. See
+    /// [`Uuid::parse_with_checksum`] for how it's calculated.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// let s = uuid.to_string_with_checksum();
+    ///
+    /// assert_eq!("550e8400-e29b-41d4-a716-446655440000:79", s);
+    /// assert_eq!(uuid, Uuid::parse_with_checksum(&s)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_string_with_checksum(&self) -> std::string::String {
+        format!("{}:{:02X}", self.hyphenated(), self.checksum())
+    }
+
+    /// Get the lower-case hyphenated string of the UUID wrapped in
+    /// parentheses.
+    ///
+    /// This is the `P` format of .NET's `Guid.ToString`, and can be parsed
+    /// back with [`Uuid::parse_str`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!("(550e8400-e29b-41d4-a716-446655440000)", uuid.to_parens());
+    /// assert_eq!(uuid, Uuid::parse_str(&uuid.to_parens())?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_parens(&self) -> std::string::String {
+        format!("({})", self.hyphenated())
+    }
+
+    /// Get the string of the UUID in one of the formats of .NET's
+    /// `Guid.ToString`.
+    ///
+    /// The supported format specifiers are:
+    ///
+    /// * `N`: simple, like [`Uuid::simple`].
+    /// * `D`: hyphenated, like [`Uuid::hyphenated`].
+    /// * `B`: braced, like [`Uuid::braced`].
+    /// * `P`: parenthesized, like [`Uuid::to_parens`].
+    ///
+    /// As in .NET, specifiers are case-insensitive and the hex digits are
+    /// always lower-case.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `format` is any other character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!("550e8400e29b41d4a716446655440000", uuid.to_dotnet('N')?);
+    /// assert_eq!("(550e8400-e29b-41d4-a716-446655440000)", uuid.to_dotnet('P')?);
+    ///
+    /// assert!(uuid.to_dotnet('X').is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_dotnet(&self, format: char) -> Result<std::string::String, crate::Error> {
+        use crate::std::string::ToString;
+
+        match format {
+            'N' | 'n' => Ok(self.simple().to_string()),
+            'D' | 'd' => Ok(self.hyphenated().to_string()),
+            'B' | 'b' => Ok(self.braced().to_string()),
+            'P' | 'p' => Ok(self.to_parens()),
+            format => Err(crate::Error(crate::error::ErrorKind::DotnetFormat {
+                format,
+            })),
+        }
+    }
+
+    /// Write the lower-case string of the UUID in the given format into a
+    /// bounded buffer.
+    ///
+    /// On success this returns `Ok` with the number of bytes written to the
+    /// start of `out`. If `out` is too small, nothing is written and this
+    /// returns `Err` with the number of bytes that are needed. This maps
+    /// onto the usual bounded buffer idiom of C APIs. No terminating `NUL`
+    /// is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Format, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// let mut buf = [0; 40];
+    /// let len = uuid.format_into(&mut buf, Format::Braced).unwrap();
+    ///
+    /// assert_eq!(b"{550e8400-e29b-41d4-a716-446655440000}", &buf[..len]);
+    ///
+    /// assert_eq!(Err(45), uuid.format_into(&mut buf, Format::Urn));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn format_into(&self, out: &mut [u8], format: Format) -> Result<usize, usize> {
+        let len = match format {
+            Format::Simple => Simple::LENGTH,
+            Format::Hyphenated => Hyphenated::LENGTH,
+            Format::Urn => Urn::LENGTH,
+            Format::Braced | Format::Parenthesized => Braced::LENGTH,
+        };
+
+        if out.len() < len {
+            return Err(len);
+        }
+
+        let src = self.as_bytes();
+
+        match format {
+            Format::Simple => {
+                encode_simple(src, out, false);
+            }
+            Format::Hyphenated => {
+                encode_hyphenated(src, out, false);
+            }
+            Format::Urn => {
+                encode_urn(src, out, false);
+            }
+            Format::Braced => {
+                encode_braced(src, out, false);
+            }
+            Format::Parenthesized => {
+                encode_braced(src, out, false);
+
+                out[0] = b'(';
+                out[len - 1] = b')';
+            }
+        }
+
+        Ok(len)
+    }
+
+    /// Get the 22 character shortuuid string of the UUID.
+    ///
+    /// See [`Uuid::from_shortuuid`] for a description of the format.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("3b1f8b40-222c-4a6e-b77e-779d5a94e21c")?;
+    ///
+    /// assert_eq!("CXc85b4rqinB7s5J52TRYb", uuid.to_shortuuid());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_shortuuid(&self) -> std::string::String {
+        let alphabet = crate::parser::SHORTUUID_ALPHABET;
+
+        let mut value = self.as_u128();
+        let mut buf = [alphabet[0]; 22];
+
+        for b in buf.iter_mut().rev() {
+            *b = alphabet[(value % 57) as usize];
+            value /= 57;
+        }
+
+        buf.iter().map(|&b| b as char).collect()
+    }
+
+    /// Get the decimal string of the UUID's 128 bit value.
+    ///
+    /// The UUID is treated as an unsigned big-endian integer, as in
+    /// [`Uuid::as_u128`], and written without leading zeros. See
+    /// [`Uuid::from_decimal_str`] for parsing it back.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!(
+    ///     "113059749145936325402354257176981405696",
+    ///     uuid.to_decimal_string(),
+    /// );
+    /// assert_eq!("0", Uuid::nil().to_decimal_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_decimal_string(&self) -> std::string::String {
+        use crate::std::string::ToString;
+
+        self.as_u128().to_string()
+    }
+
+    /// Get the string of the UUID in the given representation.
+    ///
+    /// This is the inverse of [`Uuid::parse`]. [`Repr::Hex`] is encoded as a
+    /// lower-case hyphenated string, [`Repr::Decimal`] as in
+    /// [`Uuid::to_decimal_string`], and [`Repr::ShortUuid`] as in
+    /// [`Uuid::to_shortuuid`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Repr, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!("550e8400-e29b-41d4-a716-446655440000", uuid.encode_repr(Repr::Hex));
+    /// assert_eq!(
+    ///     "113059749145936325402354257176981405696",
+    ///     uuid.encode_repr(Repr::Decimal),
+    /// );
+    ///
+    /// for repr in [Repr::Hex, Repr::Decimal, Repr::ShortUuid] {
+    ///     assert_eq!(uuid, Uuid::parse(&uuid.encode_repr(repr), repr)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn encode_repr(&self, repr: Repr) -> std::string::String {
+        use crate::std::string::ToString;
+
+        match repr {
+            Repr::Hex => self.hyphenated().to_string(),
+            Repr::Decimal => self.to_decimal_string(),
+            Repr::ShortUuid => self.to_shortuuid(),
+        }
+    }
+
+    /// Get the lower-case hyphenated string of the UUID, using `sep` in
+    /// place of the hyphens.
+    ///
+    /// The groups are still split at the standard 8-4-4-4-12 positions.
+    /// Strings using any separator other than `-` won't parse back into a
+    /// UUID with [`Uuid::parse_str`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sep` isn't an ASCII character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!("550e8400.e29b.41d4.a716.446655440000", uuid.to_delimited('.'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_delimited(&self, sep: char) -> std::string::String {
+        assert!(sep.is_ascii(), "the separator must be an ASCII character");
+
+        self.to_hyphenated_bytes()
+            .iter()
+            .map(|&b| if b == b'-' { sep } else { b as char })
+            .collect()
+    }
+
+    /// Get the lower-case simple string of the UUID split into groups of
+    /// `chunk` characters, separated by `sep`.
+    ///
+    /// If `chunk` doesn't evenly divide the 32 characters then the last
+    /// group is shorter. This is meant for display codes that are easier for
+    /// people to read back. Unless `chunk` is 32, the string won't parse
+    /// back into a UUID with [`Uuid::parse_str`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!(
+    ///     "550e 8400 e29b 41d4 a716 4466 5544 0000",
+    ///     uuid.to_grouped(4, ' '),
+    /// );
+    /// assert_eq!(
+    ///     "550e8400e2_9b41d4a716_4466554400_00",
+    ///     uuid.to_grouped(10, '_'),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_grouped(&self, chunk: usize, sep: char) -> std::string::String {
+        assert!(chunk > 0, "the chunk size must be greater than zero");
+
+        let mut grouped = std::string::String::new();
+
+        for (i, group) in self.to_simple_bytes().chunks(chunk).enumerate() {
+            if i > 0 {
+                grouped.push(sep);
+            }
+
+            grouped.extend(group.iter().map(|&b| b as char));
+        }
+
+        grouped
+    }
+
+    /// Write the lower-case hyphenated string of the UUID to `writer`.
+    ///
+    /// The string is encoded into a buffer on the stack and written with a
+    /// single call to [`Write::write_all`], so no allocations are made.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// let mut buf = Vec::new();
+    /// uuid.write_hyphenated(&mut buf)?;
+    ///
+    /// assert_eq!(b"550e8400-e29b-41d4-a716-446655440000", &*buf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Write::write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+    #[cfg(feature = "std")]
+    pub fn write_hyphenated<W: std::io::Write + ?Sized>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        writer.write_all(
+            self.hyphenated()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes(),
+        )
+    }
+
+    /// Write the lower-case simple string of the UUID to `writer`.
+    ///
+    /// See [`Uuid::write_hyphenated`] for more details.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// let mut buf = Vec::new();
+    /// uuid.write_simple(&mut buf)?;
+    ///
+    /// assert_eq!(b"550e8400e29b41d4a716446655440000", &*buf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_simple<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(
+            self.simple()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes(),
+        )
+    }
+
+    /// Write the lower-case URN string of the UUID to `writer`.
+    ///
+    /// See [`Uuid::write_hyphenated`] for more details.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// let mut buf = Vec::new();
+    /// uuid.write_urn(&mut buf)?;
+    ///
+    /// assert_eq!(b"urn:uuid:550e8400-e29b-41d4-a716-446655440000", &*buf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_urn<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(
+            self.urn()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes(),
+        )
+    }
+
+    /// Write the lower-case braced string of the UUID to `writer`.
+    ///
+    /// See [`Uuid::write_hyphenated`] for more details.
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// let mut buf = Vec::new();
+    /// uuid.write_braced(&mut buf)?;
+    ///
+    /// assert_eq!(b"{550e8400-e29b-41d4-a716-446655440000}", &*buf);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_braced<W: std::io::Write + ?Sized>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(
+            self.braced()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes(),
+        )
+    }
+}
+
+struct Short(u32);
+
+impl fmt::Display for Short {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08x}", self.0)
+    }
 }
 
 const UPPER: [u8; 16] = [
@@ -143,7 +960,7 @@ const fn format_simple(src: &[u8; 16], upper: bool) -> [u8; 32] {
     let lut = if upper { &UPPER } else { &LOWER };
     let mut dst = [0; 32];
     let mut i = 0;
-    while i < 16 {
+    while i < Uuid::BYTES_LEN {
         let x = src[i];
         dst[i * 2] = lut[(x >> 4) as usize];
         dst[i * 2 + 1] = lut[(x & 0x0f) as usize];
@@ -669,6 +1486,179 @@ impl Simple {
     }
 }
 
+impl SimpleStr {
+    /// Get the string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let simple = Uuid::nil().to_simple_str();
+    /// assert_eq!(simple.as_str(), "00000000000000000000000000000000");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the buffer only contains ASCII hex digits
+        unsafe { str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Parses the string back into a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// let simple = Uuid::nil().to_simple_str();
+    /// assert_eq!(simple.to_uuid(), Uuid::nil());
+    /// ```
+    pub fn to_uuid(&self) -> Uuid {
+        match Uuid::try_parse_ascii(&self.0) {
+            Ok(uuid) => uuid,
+            Err(_) => unreachable!("a `SimpleStr` is always a valid simple UUID"),
+        }
+    }
+}
+
+impl fmt::Display for SimpleStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, self.as_str())
+    }
+}
+
+impl fmt::Debug for SimpleStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl hash::Hash for SimpleStr {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // Hash as a `str` so it's consistent with the `Borrow<str>` impl
+        self.as_str().hash(state)
+    }
+}
+
+impl Borrow<str> for SimpleStr {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SimpleStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Uuid> for SimpleStr {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        uuid.to_simple_str()
+    }
+}
+
+impl FormattedUuid {
+    /// Creates a [`FormattedUuid`] that writes `uuid` in `format`.
+    pub const fn new(uuid: Uuid, format: Format) -> Self {
+        FormattedUuid { uuid, format }
+    }
+
+    /// Writes the [`Uuid`] in its format to `buffer` as a lower-case string.
+    ///
+    /// This is slightly more efficient than using the formatting
+    /// infrastructure as it avoids virtual calls, and may avoid
+    /// double buffering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at
+    /// least the length of the string in the format.
+    /// [`Uuid::encode_buffer`] can be used to get a sufficiently-large
+    /// temporary buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::{FormattedUuid, Format}, Uuid};
+    ///
+    /// let formatted = FormattedUuid::new(Uuid::nil(), Format::Parenthesized);
+    ///
+    /// assert_eq!(
+    ///     formatted.encode_lower(&mut Uuid::encode_buffer()),
+    ///     "(00000000-0000-0000-0000-000000000000)"
+    /// );
+    /// ```
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        match self.uuid.format_into(buffer, self.format) {
+            // SAFETY: the encoded string is ASCII
+            Ok(len) => unsafe { str::from_utf8_unchecked_mut(&mut buffer[..len]) },
+            Err(len) => panic!(
+                "the buffer must be at least {} bytes, but is {}",
+                len,
+                buffer.len()
+            ),
+        }
+    }
+
+    /// Get the [`Format`] the [`Uuid`] is written in.
+    pub const fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Get a reference to the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::{FormattedUuid, Format}, Uuid};
+    ///
+    /// let formatted = FormattedUuid::new(Uuid::nil(), Format::Urn);
+    /// assert_eq!(*formatted.as_uuid(), Uuid::nil());
+    /// ```
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Consumes the [`FormattedUuid`], returning the underlying [`Uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::{FormattedUuid, Format}, Uuid};
+    ///
+    /// let formatted = FormattedUuid::new(Uuid::nil(), Format::Urn);
+    /// assert_eq!(formatted.into_uuid(), Uuid::nil());
+    /// ```
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl fmt::Display for FormattedUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, self.encode_lower(&mut [0; Urn::LENGTH]))
+    }
+}
+
+impl From<FormattedUuid> for Uuid {
+    #[inline]
+    fn from(f: FormattedUuid) -> Self {
+        f.into_uuid()
+    }
+}
+
+impl AsRef<Uuid> for FormattedUuid {
+    #[inline]
+    fn as_ref(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
 impl Urn {
     /// The length of a URN [`Uuid`] string.
     ///
@@ -819,6 +1809,34 @@ impl Urn {
     }
 }
 
+/// Writes `s` to `f`, honoring any width, fill, and alignment.
+///
+/// This is like `Formatter::pad`, except precision is ignored so an
+/// encoded UUID is never truncated. `s` is expected to be ASCII.
+fn pad(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let padding = match f.width() {
+        Some(width) if width > s.len() => width - s.len(),
+        _ => return f.write_str(s),
+    };
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+
+    Ok(())
+}
+
 macro_rules! impl_fmt_traits {
     ($($T:ident<$($a:lifetime),*>),+) => {$(
         impl<$($a),*> fmt::Display for $T<$($a),*> {
@@ -830,13 +1848,28 @@ macro_rules! impl_fmt_traits {
 
         impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
+                pad(f, self.encode_lower(&mut [0; Self::LENGTH]))
             }
         }
 
         impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
+                pad(f, self.encode_upper(&mut [0; Self::LENGTH]))
+            }
+        }
+
+        impl<$($a),*> $T<$($a),*> {
+            /// Get an iterator over the lower-case characters of the string,
+            /// without allocating.
+            pub fn chars(&self) -> Chars {
+                let mut buf = [0; Urn::LENGTH];
+                let back = self.encode_lower(&mut buf).len();
+
+                Chars {
+                    buf,
+                    front: 0,
+                    back,
+                }
             }
         }
 
@@ -912,6 +1945,106 @@ impl_fmt_traits! {
     Braced<>
 }
 
+impl Iterator for Chars {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if self.front < self.back {
+            self.front += 1;
+
+            // The buffer only contains ASCII, so each byte is a `char`
+            Some(self.buf[self.front - 1] as char)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Chars {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            Some(self.buf[self.back] as char)
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for Chars {}
+
+impl iter::FusedIterator for Chars {}
+
+/// A formatter that reuses one buffer to write many [`Uuid`]s as
+/// lower-case hyphenated strings.
+///
+/// Each call to [`format`](#method.format) overwrites the buffer and returns
+/// a string borrowed from it, so writing a column of UUIDs doesn't allocate
+/// per row. The borrow checker prevents the formatter from being reused
+/// while the previous string is still in use.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{fmt::BatchFormatter, Uuid};
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuids = [
+///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+///     Uuid::nil(),
+/// ];
+///
+/// let mut formatter = BatchFormatter::new();
+/// let mut csv = String::new();
+///
+/// for uuid in &uuids {
+///     csv.push_str(formatter.format(uuid));
+///     csv.push('\n');
+/// }
+///
+/// assert_eq!(
+///     "67e55044-10b1-426f-9247-bb680e5fe0c8\n00000000-0000-0000-0000-000000000000\n",
+///     csv,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BatchFormatter {
+    buf: [u8; Hyphenated::LENGTH],
+}
+
+impl BatchFormatter {
+    /// Creates a formatter with an empty buffer.
+    pub const fn new() -> Self {
+        BatchFormatter {
+            buf: [0; Hyphenated::LENGTH],
+        }
+    }
+
+    /// Writes `uuid` into the buffer as a lower-case hyphenated string,
+    /// returning the string.
+    pub fn format(&mut self, uuid: &Uuid) -> &str {
+        uuid.hyphenated().encode_lower(&mut self.buf)
+    }
+}
+
+impl Default for BatchFormatter {
+    #[inline]
+    fn default() -> Self {
+        BatchFormatter::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -980,6 +2113,517 @@ mod tests {
         assert!(buf[len..].iter().all(|x| *x == b'x'));
     }
 
+    #[test]
+    fn hyphenated_bytes() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            &uuid.to_hyphenated_bytes()[..],
+            uuid.hyphenated()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes()
+        );
+    }
+
+    #[test]
+    fn hyphenated_bytes_const() {
+        const UUID: Uuid = crate::tests::new();
+        const HYPHENATED: [u8; Hyphenated::LENGTH] = UUID.to_hyphenated_bytes();
+        const SIMPLE: [u8; Simple::LENGTH] = UUID.to_simple_bytes();
+        const SIMPLE_UPPER: [u8; Simple::LENGTH] = UUID.to_simple_bytes_upper();
+
+        assert_eq!(&HYPHENATED, b"f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4");
+        assert_eq!(&SIMPLE, b"f9168c5eceb24faab6bf329bf39fa1e4");
+        assert_eq!(&SIMPLE_UPPER, b"F9168C5ECEB24FAAB6BF329BF39FA1E4");
+    }
+
+    #[test]
+    fn simple_bytes() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            &uuid.to_simple_bytes()[..],
+            uuid.simple()
+                .encode_lower(&mut Uuid::encode_buffer())
+                .as_bytes()
+        );
+        assert_eq!(
+            &uuid.to_simple_bytes_upper()[..],
+            uuid.simple()
+                .encode_upper(&mut Uuid::encode_buffer())
+                .as_bytes()
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            format!("{:?}", uuid)
+        );
+
+        assert_eq!(
+            "Uuid {
+    version: 4,
+    variant: RFC4122,
+    time_low: 0xf9168c5e,
+    time_mid: 0xceb2,
+    time_hi_and_version: 0x4faa,
+    clock_seq: 0xb6bf,
+    node: 0x329bf39fa1e4,
+}",
+            format!("{:#?}", uuid)
+        );
+
+        // Fields are zero-padded to their full width
+        let nil = format!("{:#?}", Uuid::nil());
+
+        assert!(nil.contains("time_low: 0x00000000,"));
+        assert!(nil.contains("time_mid: 0x0000,"));
+        assert!(nil.contains("node: 0x000000000000,"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn tag_uri() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            "tag:timothy@hoax.test,2004-05-20:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            uuid.to_tag_uri("timothy@hoax.test", "2004-05-20")
+        );
+        assert_eq!(
+            format!("tag:example.com,2023:{}", uuid.hyphenated()),
+            uuid.to_tag_uri("example.com", "2023")
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn delimited() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+
+        assert_eq!(uuid.hyphenated().to_string(), uuid.to_delimited('-'));
+        assert_eq!(
+            "f9168c5e.ceb2.4faa.b6bf.329bf39fa1e4",
+            uuid.to_delimited('.')
+        );
+        assert_eq!(
+            "f9168c5e ceb2 4faa b6bf 329bf39fa1e4",
+            uuid.to_delimited(' ')
+        );
+
+        assert!(Uuid::parse_str(&uuid.to_delimited(':')).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn delimited_non_ascii() {
+        crate::tests::new().to_delimited('·');
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write() {
+        use crate::std::{io::Write, string::ToString, vec::Vec};
+
+        let uuid = crate::tests::new();
+
+        let mut buf = Vec::new();
+
+        uuid.write_hyphenated(&mut buf).unwrap();
+        buf.push(b'\n');
+        uuid.write_simple(&mut buf).unwrap();
+        buf.push(b'\n');
+        uuid.write_urn(&mut buf).unwrap();
+        buf.push(b'\n');
+        uuid.write_braced(&mut buf).unwrap();
+
+        assert_eq!(
+            format!(
+                "{}\n{}\n{}\n{}",
+                uuid.hyphenated(),
+                uuid.simple(),
+                uuid.urn(),
+                uuid.braced()
+            ),
+            crate::std::str::from_utf8(&buf).unwrap()
+        );
+
+        // Writers that run out of space return an error
+        let mut small = [0u8; 35];
+        assert!(uuid.write_hyphenated(&mut &mut small[..]).is_err());
+
+        // Unsized writers are supported
+        let mut buf = Vec::new();
+        let writer: &mut dyn Write = &mut buf;
+        uuid.write_hyphenated(writer).unwrap();
+
+        assert_eq!(uuid.to_string().as_bytes(), &*buf);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn string_with_checksum() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:CB",
+            uuid.to_string_with_checksum()
+        );
+        assert_eq!(
+            "00000000-0000-0000-0000-000000000000:00",
+            Uuid::nil().to_string_with_checksum()
+        );
+        assert_eq!(
+            Ok(uuid),
+            Uuid::parse_with_checksum(&uuid.to_string_with_checksum())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parens() {
+        let uuid = crate::tests::new();
+
+        assert_eq!("(f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)", uuid.to_parens());
+        assert_eq!(Ok(uuid), Uuid::parse_str(&uuid.to_parens()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn variant_and_version() {
+        use crate::std::string::ToString;
+
+        assert_eq!("RFC4122", Variant::RFC4122.to_string());
+        assert_eq!("Microsoft", Variant::Microsoft.to_string());
+
+        assert_eq!("nil", Version::Nil.to_string());
+        assert_eq!("v1 (time-based)", Version::Mac.to_string());
+        assert_eq!("v4 (random)", Version::Random.to_string());
+        assert_eq!("v5 (SHA-1 hash)", Version::Sha1.to_string());
+
+        let uuid = crate::tests::new();
+        assert_eq!(
+            "v4 (random), RFC4122",
+            format!("{}, {}", uuid.get_version().unwrap(), uuid.get_variant())
+        );
+    }
+
+    #[test]
+    fn format_into() {
+        let uuid = crate::tests::new();
+
+        for &(format, expected) in &[
+            (Format::Simple, "f9168c5eceb24faab6bf329bf39fa1e4"),
+            (Format::Hyphenated, "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+            (Format::Urn, "urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"),
+            (Format::Braced, "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}"),
+            (
+                Format::Parenthesized,
+                "(f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)",
+            ),
+        ] {
+            let mut buf = [b'x'; 50];
+            assert_eq!(Ok(expected.len()), uuid.format_into(&mut buf, format));
+            assert_eq!(expected.as_bytes(), &buf[..expected.len()]);
+            assert!(buf[expected.len()..].iter().all(|&b| b == b'x'));
+
+            let mut exact = [0; 45];
+            assert_eq!(
+                Ok(expected.len()),
+                uuid.format_into(&mut exact[..expected.len()], format)
+            );
+
+            let mut short = [b'x'; 45];
+            assert_eq!(
+                Err(expected.len()),
+                uuid.format_into(&mut short[..expected.len() - 1], format)
+            );
+            assert_eq!(Err(expected.len()), uuid.format_into(&mut [], format));
+            assert!(short.iter().all(|&b| b == b'x'));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dotnet() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+
+        for (format, expected) in [
+            ('N', uuid.simple().to_string()),
+            ('D', uuid.hyphenated().to_string()),
+            ('B', uuid.braced().to_string()),
+            ('P', uuid.to_parens()),
+        ] {
+            assert_eq!(Ok(&expected), uuid.to_dotnet(format).as_ref());
+            assert_eq!(
+                Ok(&expected),
+                uuid.to_dotnet(format.to_ascii_lowercase()).as_ref()
+            );
+            assert_eq!(Ok(uuid), Uuid::parse_str(&expected));
+        }
+
+        assert_eq!(
+            "invalid format: expected one of `N`, `D`, `B`, or `P`, found `X`",
+            uuid.to_dotnet('X').unwrap_err().to_string()
+        );
+        assert!(uuid.to_dotnet('-').is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn shortuuid() {
+        let uuid = crate::tests::new();
+
+        assert_eq!("nL9J5yU6xWeCHWdWD7XGTK", uuid.to_shortuuid());
+        assert_eq!("2222222222222222222222", Uuid::nil().to_shortuuid());
+        assert_eq!(
+            "oZEq7ovRbLq6UnGMPwc8B5",
+            Uuid::from_u128(u128::MAX).to_shortuuid()
+        );
+
+        assert_eq!(Ok(uuid), Uuid::from_shortuuid(&uuid.to_shortuuid()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn grouped() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            "f916 8c5e ceb2 4faa b6bf 329b f39f a1e4",
+            uuid.to_grouped(4, ' ')
+        );
+        assert_eq!(
+            "f9168c5e-ceb24faa-b6bf329b-f39fa1e4",
+            uuid.to_grouped(8, '-')
+        );
+        assert_eq!(
+            "f9168-c5ece-b24fa-ab6bf-329bf-39fa1-e4",
+            uuid.to_grouped(5, '-')
+        );
+        assert_eq!(uuid.simple().to_string(), uuid.to_grouped(32, ' '));
+        assert_eq!(uuid.simple().to_string(), uuid.to_grouped(100, ' '));
+        assert_eq!(Ok(uuid), Uuid::parse_str(&uuid.to_grouped(32, ' ')));
+
+        assert_eq!(32 + 31 * "→".len(), uuid.to_grouped(1, '→').len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn grouped_zero_chunk() {
+        let _ = crate::tests::new().to_grouped(0, ' ');
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decimal() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            "331094848530093083170738142201201533412",
+            uuid.to_decimal_string()
+        );
+        assert_eq!("0", Uuid::nil().to_decimal_string());
+        assert_eq!(
+            "340282366920938463463374607431768211455",
+            Uuid::from_u128(u128::MAX).to_decimal_string()
+        );
+
+        assert_eq!(Ok(uuid), Uuid::from_decimal_str(&uuid.to_decimal_string()));
+    }
+
+    #[test]
+    fn batch_formatter() {
+        let uuid = crate::tests::new();
+        let mut formatter = BatchFormatter::default();
+
+        let mut buf = [0; Hyphenated::LENGTH];
+        assert_eq!(
+            uuid.hyphenated().encode_lower(&mut buf),
+            formatter.format(&uuid)
+        );
+        assert_eq!(
+            "00000000-0000-0000-0000-000000000000",
+            formatter.format(&Uuid::nil())
+        );
+        assert_eq!(
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            formatter.format(&Uuid::from_u128(u128::MAX))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encode_repr() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+
+        assert_eq!(uuid.hyphenated().to_string(), uuid.encode_repr(Repr::Hex));
+        assert_eq!(uuid.to_decimal_string(), uuid.encode_repr(Repr::Decimal));
+        assert_eq!(uuid.to_shortuuid(), uuid.encode_repr(Repr::ShortUuid));
+
+        for &repr in &[Repr::Hex, Repr::Decimal, Repr::ShortUuid] {
+            assert_eq!(Ok(uuid), Uuid::parse(&uuid.encode_repr(repr), repr));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn chars() {
+        use crate::std::string::{String, ToString};
+
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            uuid.hyphenated().chars().collect::<String>()
+        );
+        assert_eq!(
+            uuid.simple().to_string(),
+            uuid.simple().chars().collect::<String>()
+        );
+        assert_eq!(
+            uuid.urn().to_string(),
+            uuid.urn().chars().collect::<String>()
+        );
+        assert_eq!(
+            uuid.braced().to_string(),
+            uuid.braced().chars().collect::<String>()
+        );
+
+        let mut chars = uuid.hyphenated().chars();
+        assert_eq!(Hyphenated::LENGTH, chars.len());
+        assert_eq!(Some('4'), chars.next_back());
+        assert_eq!(
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e"
+                .chars()
+                .rev()
+                .collect::<String>(),
+            chars.by_ref().rev().collect::<String>()
+        );
+        assert_eq!(None, chars.next());
+        assert_eq!(None, chars.next_back());
+        assert_eq!(0, chars.len());
+    }
+
+    #[test]
+    fn padding() {
+        let uuid = crate::tests::new();
+
+        assert_eq!(
+            "    f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            format!("{:>40}", uuid)
+        );
+        assert_eq!(
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4    |",
+            format!("{:<40}|", uuid)
+        );
+        assert_eq!(
+            "**F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4**",
+            format!("{:*^40X}", uuid)
+        );
+        assert_eq!(
+            "  f9168c5eceb24faab6bf329bf39fa1e4",
+            format!("{:>34}", uuid.simple())
+        );
+        assert_eq!(
+            "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}--",
+            format!("{:-<40}", uuid.braced())
+        );
+        assert_eq!(
+            "urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            format!("{:10}", uuid.urn())
+        );
+        assert_eq!(
+            "f9168c5eceb24faab6bf329bf39fa1e4  ",
+            format!("{:34}", uuid.to_simple_str())
+        );
+
+        // Precision doesn't truncate the UUID
+        assert_eq!(
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            format!("{:.8}", uuid)
+        );
+        assert_eq!(
+            "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4    ",
+            format!("{:<40.8X}", uuid)
+        );
+        assert_eq!(
+            "f9168c5eceb24faab6bf329bf39fa1e4",
+            format!("{:.3}", uuid.simple())
+        );
+        assert_eq!(
+            "f9168c5eceb24faab6bf329bf39fa1e4",
+            format!("{:.3}", uuid.to_simple_str())
+        );
+        assert_eq!(
+            "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+            format!("{:.3}", FormattedUuid::new(uuid, Format::Braced))
+        );
+    }
+
+    #[test]
+    fn simple_str() {
+        use crate::std::{collections::HashMap, string::ToString};
+
+        let uuid = crate::tests::new();
+        let simple = uuid.to_simple_str();
+
+        assert_eq!(uuid.simple().to_string(), simple.as_str());
+        assert_eq!(uuid.simple().to_string(), simple.to_string());
+        assert_eq!(uuid, simple.to_uuid());
+        assert_eq!(simple, SimpleStr::from(uuid));
+        assert_eq!(
+            "\"f9168c5eceb24faab6bf329bf39fa1e4\"",
+            format!("{:?}", simple)
+        );
+
+        let mut map = HashMap::new();
+        map.insert(simple, 1);
+        map.insert(Uuid::nil().to_simple_str(), 2);
+
+        assert_eq!(Some(&1), map.get("f9168c5eceb24faab6bf329bf39fa1e4"));
+        assert_eq!(Some(&2), map.get("00000000000000000000000000000000"));
+        assert_eq!(None, map.get("F9168C5ECEB24FAAB6BF329BF39FA1E4"));
+
+        // Ordering matches the strings
+        assert!(Uuid::nil().to_simple_str() < simple);
+        assert_eq!(
+            Uuid::nil().to_simple_str().as_str() < simple.as_str(),
+            Uuid::nil().to_simple_str() < simple
+        );
+    }
+
+    #[test]
+    fn short() {
+        use crate::std::string::ToString;
+
+        let uuid = crate::tests::new();
+
+        assert_eq!(&uuid.to_string()[..8], uuid.short().to_string());
+
+        assert_eq!("00000000", Uuid::nil().short().to_string());
+        assert_eq!(
+            "0000000a",
+            Uuid::from_fields(0xa, 0xffff, 0xffff, &[0xff; 8])
+                .short()
+                .to_string()
+        );
+    }
+
     #[test]
     #[should_panic]
     fn hyphenated_too_small() {
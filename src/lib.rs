@@ -108,6 +108,8 @@
 //! * `fast-rng` - uses a faster algorithm for generating random UUIDs.
 //!   This feature requires more dependencies to compile, but is just as suitable for
 //!   UUIDs as the default algorithm.
+//! * `test-util` - adds the [`test_util`] module for generating reproducible
+//!   version 4 UUIDs in tests.
 //!
 //! # Unstable features
 //!
@@ -227,6 +229,7 @@ use zerocopy::{AsBytes, FromBytes, Unaligned};
 
 mod builder;
 mod error;
+mod non_nil;
 mod parser;
 
 pub mod fmt;
@@ -237,6 +240,12 @@ pub use timestamp::{context::NoContext, ClockSequence, Timestamp};
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub use timestamp::context::Context;
 
+#[cfg(feature = "v3")]
+pub use v3::{Md5Hasher, NamespaceHasher};
+
+#[cfg(feature = "v5")]
+pub use v5::{V5Context, V5Hasher};
+
 #[cfg(feature = "v1")]
 #[doc(hidden)]
 // Soft-deprecated (Rust doesn't support deprecating re-exports)
@@ -262,6 +271,9 @@ mod rng;
 #[cfg(feature = "sha1")]
 mod sha1;
 
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
 mod external;
 
 #[macro_use]
@@ -271,9 +283,14 @@ mod macros;
 #[cfg(feature = "macro-diagnostics")]
 pub extern crate uuid_macro_internal;
 
-use crate::std::convert;
+use crate::std::{convert, ops};
 
-pub use crate::{builder::Builder, error::Error};
+pub use crate::{
+    builder::Builder,
+    error::{Error, ParseErrorKind},
+    non_nil::NonNilUuid,
+    parser::GroupSpan,
+};
 
 /// A 128-bit (16 byte) buffer containing the UUID.
 ///
@@ -317,6 +334,41 @@ pub enum Version {
     Max = 0xff,
 }
 
+impl Version {
+    /// Gets the version for a version number, as returned by
+    /// [`Uuid::get_version_num`].
+    ///
+    /// Returns `None` if the number isn't a recognized version. The number `0`
+    /// is the version of the nil UUID, so is recognized as [`Version::Nil`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Version;
+    /// assert_eq!(Some(Version::Random), Version::from_number(4));
+    /// assert_eq!(None, Version::from_number(9));
+    /// ```
+    pub const fn from_number(n: u8) -> Option<Version> {
+        match n {
+            0 => Some(Version::Nil),
+            1 => Some(Version::Mac),
+            2 => Some(Version::Dce),
+            3 => Some(Version::Md5),
+            4 => Some(Version::Random),
+            5 => Some(Version::Sha1),
+            #[cfg(uuid_unstable)]
+            6 => Some(Version::SortMac),
+            #[cfg(uuid_unstable)]
+            7 => Some(Version::SortRand),
+            #[cfg(uuid_unstable)]
+            8 => Some(Version::Custom),
+            #[cfg(uuid_unstable)]
+            0xf => Some(Version::Max),
+            _ => None,
+        }
+    }
+}
+
 /// The reserved variants of UUIDs.
 ///
 /// # References
@@ -336,6 +388,33 @@ pub enum Variant {
     Future,
 }
 
+impl Variant {
+    /// Gets the variant encoded in octet 8 of a UUID.
+    ///
+    /// Only the high bits of the octet are used, so any value maps to a
+    /// variant. See [`Uuid::get_variant`] for the bit patterns of each one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Variant;
+    /// assert_eq!(Variant::RFC4122, Variant::from_byte(0xa7));
+    /// assert_eq!(Variant::NCS, Variant::from_byte(0x7f));
+    /// ```
+    pub const fn from_byte(octet8: u8) -> Variant {
+        match octet8 {
+            x if x & 0x80 == 0x00 => Variant::NCS,
+            x if x & 0xc0 == 0x80 => Variant::RFC4122,
+            x if x & 0xe0 == 0xc0 => Variant::Microsoft,
+            x if x & 0xe0 == 0xe0 => Variant::Future,
+            // The above match arms are actually exhaustive
+            // We just return `Future` here because we can't
+            // use `unreachable!()` in a `const fn`
+            _ => Variant::Future,
+        }
+    }
+}
+
 /// A Universally Unique Identifier (UUID).
 ///
 /// # Examples
@@ -460,12 +539,35 @@ impl Uuid {
         0xc8,
     ]);
 
+    /// The number of bytes in a UUID.
+    ///
+    /// This is the length of [`Bytes`], and of the slices accepted by
+    /// [`Uuid::from_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut buf = [0; 2 * Uuid::BYTES_LEN];
+    ///
+    /// buf[..Uuid::BYTES_LEN].copy_from_slice(Uuid::NAMESPACE_DNS.as_bytes());
+    /// buf[Uuid::BYTES_LEN..].copy_from_slice(Uuid::NAMESPACE_URL.as_bytes());
+    /// ```
+    pub const BYTES_LEN: usize = 16;
+
     /// Returns the variant of the UUID structure.
     ///
     /// This determines the interpretation of the structure of the UUID.
     /// This method simply reads the value of the variant byte. It doesn't
     /// validate the rest of the UUID as conforming to that variant.
     ///
+    /// The variant is read from the most significant bits of octet 8:
+    ///
+    /// * `0xx` is [`Variant::NCS`].
+    /// * `10x` is [`Variant::RFC4122`].
+    /// * `110` is [`Variant::Microsoft`].
+    /// * `111` is [`Variant::Future`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -484,16 +586,7 @@ impl Uuid {
     ///
     /// * [Variant in RFC4122](http://tools.ietf.org/html/rfc4122#section-4.1.1)
     pub const fn get_variant(&self) -> Variant {
-        match self.as_bytes()[8] {
-            x if x & 0x80 == 0x00 => Variant::NCS,
-            x if x & 0xc0 == 0x80 => Variant::RFC4122,
-            x if x & 0xe0 == 0xc0 => Variant::Microsoft,
-            x if x & 0xe0 == 0xe0 => Variant::Future,
-            // The above match arms are actually exhaustive
-            // We just return `Future` here because we can't
-            // use `unreachable!()` in a `const fn`
-            _ => Variant::Future,
-        }
+        Variant::from_byte(self.as_bytes()[8])
     }
 
     /// Returns the version number of the UUID.
@@ -550,22 +643,133 @@ impl Uuid {
     /// * [Version in RFC4122](https://datatracker.ietf.org/doc/html/rfc4122#section-4.1.3)
     pub const fn get_version(&self) -> Option<Version> {
         match self.get_version_num() {
-            0 if self.is_nil() => Some(Version::Nil),
-            1 => Some(Version::Mac),
-            2 => Some(Version::Dce),
-            3 => Some(Version::Md5),
-            4 => Some(Version::Random),
-            5 => Some(Version::Sha1),
-            #[cfg(uuid_unstable)]
-            6 => Some(Version::SortMac),
-            #[cfg(uuid_unstable)]
-            7 => Some(Version::SortRand),
-            #[cfg(uuid_unstable)]
-            8 => Some(Version::Custom),
-            #[cfg(uuid_unstable)]
-            0xf => Some(Version::Max),
-            _ => None,
+            // Only the nil UUID itself is considered `Version::Nil`
+            0 if !self.is_nil() => None,
+            n => Version::from_number(n as u8),
+        }
+    }
+
+    /// Returns whether the UUID is of the given version.
+    ///
+    /// This is the same as comparing the result of [`Uuid::get_version`],
+    /// so UUIDs with an unknown version are never of any version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let my_uuid = Uuid::parse_str("02f09a3f-1624-3b1d-8409-44eff7708208")?;
+    ///
+    /// assert!(my_uuid.is_version(Version::Md5));
+    /// assert!(!my_uuid.is_version(Version::Random));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn is_version(&self, version: Version) -> bool {
+        match self.get_version() {
+            Some(v) => v as u8 == version as u8,
+            None => false,
+        }
+    }
+
+    /// Returns whether the UUID is of any of the given versions.
+    ///
+    /// This is useful for checking UUIDs against a policy of which versions
+    /// are accepted. See [`Uuid::is_version`] for how each version is
+    /// compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let accepted = [Version::Random, Version::Sha1];
+    ///
+    /// let random = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    /// let md5 = Uuid::parse_str("02f09a3f-1624-3b1d-8409-44eff7708208")?;
+    ///
+    /// assert!(random.is_any_version(&accepted));
+    /// assert!(!md5.is_any_version(&accepted));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn is_any_version(&self, versions: &[Version]) -> bool {
+        let mut i = 0;
+
+        while i < versions.len() {
+            if self.is_version(versions[i]) {
+                return true;
+            }
+
+            i += 1;
+        }
+
+        false
+    }
+
+    /// Returns whether the byte order of the UUID reflects the time it was
+    /// generated.
+    ///
+    /// This is true for version 6 and version 7 UUIDs, which put the most
+    /// significant bits of their timestamp first. Comparing the bytes of
+    /// these UUIDs, as the `Ord` implementation does, sorts them roughly by
+    /// creation time.
+    ///
+    /// Version 1 UUIDs also contain a timestamp, but its low bits come first,
+    /// so their byte order doesn't reflect time and this returns false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let v7 = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    /// let v1 = Uuid::parse_str("a1a2a3a4-b1b2-11c2-8000-010203040506")?;
+    ///
+    /// assert!(v7.is_time_ordered());
+    /// assert!(!v1.is_time_ordered());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn is_time_ordered(&self) -> bool {
+        matches!(self.get_version_num(), 6 | 7)
+    }
+
+    /// Counts the UUIDs in `uuids` by their version number.
+    ///
+    /// The returned array is indexed by the raw version nibble, as returned
+    /// by [`Uuid::get_version_num`], so versions that aren't recognized by
+    /// [`Uuid::get_version`] are still counted.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuids = [
+    ///     Uuid::parse_str("02f09a3f-1624-3b1d-8409-44eff7708208")?,
+    ///     Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///     Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8")?,
+    /// ];
+    ///
+    /// let histogram = Uuid::version_histogram(uuids.iter().copied());
+    ///
+    /// assert_eq!(1, histogram[3]);
+    /// assert_eq!(2, histogram[4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn version_histogram<I: IntoIterator<Item = Uuid>>(uuids: I) -> [u32; 16] {
+        let mut histogram = [0; 16];
+
+        for uuid in uuids {
+            histogram[uuid.get_version_num()] += 1;
         }
+
+        histogram
     }
 
     /// Returns the four field values of the UUID.
@@ -586,7 +790,9 @@ impl Uuid {
     /// * The last field value represents the last two groups of four and twelve
     ///   hex digits, taken in order.  The first 1-3 bits of this indicate the
     ///   UUID variant, and for V1 UUIDs, the next 13-15 bits indicate the clock
-    ///   sequence and the last 48 bits indicate the node ID.
+    ///   sequence and the last 48 bits indicate the node ID.  The bytes are
+    ///   never swapped, so they're in big-endian order like the other fields,
+    ///   even in [`Uuid::to_fields_le`].
     ///
     /// # Examples
     ///
@@ -743,6 +949,89 @@ impl Uuid {
         ((value >> 64) as u64, value as u64)
     }
 
+    /// Returns the clock sequence and node fields as a `u64`.
+    ///
+    /// This reads octets 8 to 15 of the UUID as a big-endian integer, so the
+    /// clock sequence is in the 16 most significant bits and the node in the
+    /// 48 least significant. It's the same as the second value returned by
+    /// [`Uuid::as_u64_pair`].
+    ///
+    /// The version and variant of the UUID aren't checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+    ///
+    /// assert_eq!(0xa76500a0c91e6bf6, uuid.clock_seq_and_node());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn clock_seq_and_node(&self) -> u64 {
+        self.as_u128() as u64
+    }
+
+    /// Returns the value as the two `UInt64` fields of a Cap'n Proto struct.
+    ///
+    /// The first value holds the 64 most significant bits of the UUID and
+    /// the second the 64 least significant, both as big-endian integers, so
+    /// the hex digits of the UUID read the same as the two fields. This is
+    /// the same as [`Uuid::as_u64_pair`], and the inverse of
+    /// [`Uuid::from_capnp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    /// assert_eq!(uuid.to_capnp(), (0xa1a2a3a4b1b2c1c2, 0xd1d2d3d4d5d6d7d8));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_capnp(&self) -> (u64, u64) {
+        self.as_u64_pair()
+    }
+
+    /// Returns a 32bit value and an array of 12 octets containing the value.
+    ///
+    /// The first value is the 32 most significant bits of the UUID, which can
+    /// be used as a partition key. The array contains the remaining 12 bytes.
+    /// The UUID can be recreated with [`Uuid::join_high32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    /// assert_eq!(
+    ///     uuid.split_high32(),
+    ///     (
+    ///         0xa1a2a3a4,
+    ///         [
+    ///             0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7,
+    ///             0xd8,
+    ///         ],
+    ///     ),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn split_high32(&self) -> (u32, [u8; 12]) {
+        let bytes = self.as_bytes();
+
+        (
+            u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            [
+                bytes[4], bytes[5], bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11],
+                bytes[12], bytes[13], bytes[14], bytes[15],
+            ],
+        )
+    }
+
     /// Returns a slice of 16 octets containing the value.
     ///
     /// This method borrows the underlying byte value of the UUID.
@@ -773,6 +1062,34 @@ impl Uuid {
         &self.0
     }
 
+    /// Returns the byte of the UUID at index `i`.
+    ///
+    /// Bytes are indexed in the same order as [`Uuid::as_bytes`]. This is
+    /// the same as `self.as_bytes()[i]`, but reads more clearly when
+    /// partitioning tables of UUIDs in a `const` context.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `i` is not less than [`Uuid::BYTES_LEN`].
+    /// In a `const` context that's a compile error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{uuid, Uuid};
+    /// const IDS: [Uuid; 2] = [
+    ///     uuid!("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8"),
+    ///     uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+    /// ];
+    ///
+    /// const FIRST: [u8; 2] = [IDS[0].byte(0), IDS[1].byte(0)];
+    ///
+    /// assert_eq!([0xa1, 0x67], FIRST);
+    /// ```
+    pub const fn byte(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+
     /// Consumes self and returns the underlying byte value of the UUID.
     ///
     /// # Examples
@@ -792,6 +1109,75 @@ impl Uuid {
         self.0
     }
 
+    /// Returns the bytes of the UUID as a field value for Postgres binary
+    /// `COPY`.
+    ///
+    /// Postgres sends and receives the binary form of its `uuid` type as the
+    /// 16 bytes of the UUID in big-endian order, the same as
+    /// [`Uuid::as_bytes`]. This is also what `ToSql` in the `postgres-types`
+    /// crate writes for a `Uuid`.
+    ///
+    /// In a binary `COPY` stream each field is framed by its length, so the
+    /// returned bytes need to be preceded by the 32bit big-endian integer
+    /// `16`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// let mut field = Vec::new();
+    /// field.extend_from_slice(&16i32.to_be_bytes());
+    /// field.extend_from_slice(&uuid.to_pg_copy_bytes());
+    ///
+    /// assert_eq!(
+    ///     field,
+    ///     [
+    ///         0x00, 0x00, 0x00, 0x10, 0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2,
+    ///         0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    ///     ],
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Binary `COPY` format](https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.4)
+    pub const fn to_pg_copy_bytes(&self) -> Bytes {
+        self.0
+    }
+
+    /// Returns an IPv6 address with the same 16 octets as the UUID.
+    ///
+    /// The octets are used as-is, so this is the inverse of
+    /// [`Uuid::from_ipv6`].
+    ///
+    /// Note that usage of this method requires the `std` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::net::Ipv6Addr;
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     Ipv6Addr::new(0xa1a2, 0xa3a4, 0xb1b2, 0xc1c2, 0xd1d2, 0xd3d4, 0xd5d6, 0xd7d8),
+    ///     uuid.to_ipv6(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_ipv6(&self) -> std::net::Ipv6Addr {
+        std::net::Ipv6Addr::from(self.0)
+    }
+
     /// Returns the bytes of the UUID in little-endian order.
     ///
     /// The bytes will be flipped to convert into little-endian order. This is
@@ -824,14 +1210,311 @@ impl Uuid {
         ]
     }
 
-    /// Tests if the UUID is nil (all zeros).
-    pub const fn is_nil(&self) -> bool {
-        self.as_u128() == u128::MIN
-    }
-
-    /// Tests if the UUID is max (all ones).
-    #[cfg(uuid_unstable)]
-    pub const fn is_max(&self) -> bool {
+    /// Returns a UUID with the bytes in reverse order.
+    ///
+    /// The entire 16 byte value is flipped, so the last byte becomes the
+    /// first. This is the same transformation as round-tripping through
+    /// [`Uuid::from_u128_le`] and [`Uuid::as_u128`], rather than the
+    /// field-wise flipping done by [`Uuid::to_bytes_le`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     "d8d7d6d5-d4d3-d2d1-c2c1-b2b1a4a3a2a1",
+    ///     uuid.swap_bytes().hyphenated().to_string(),
+    /// );
+    ///
+    /// assert_eq!(Uuid::from_u128_le(uuid.as_u128()), uuid.swap_bytes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn swap_bytes(&self) -> Uuid {
+        Uuid::from_u128(self.as_u128().swap_bytes())
+    }
+
+    /// Returns a 64-bit fingerprint of the UUID.
+    ///
+    /// The fingerprint is the XOR of the high and low 64 bits of the UUID, as
+    /// returned by [`Uuid::as_u64_pair`]. It's stable across platforms and
+    /// releases of this crate, so unlike the standard [`Hash`] impl it can be
+    /// persisted or shared between processes. It isn't a cryptographic hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(0xa1a2a3a4b1b2c1c2 ^ 0xd1d2d3d4d5d6d7d8, uuid.fingerprint64());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+    pub const fn fingerprint64(&self) -> u64 {
+        let (high, low) = self.as_u64_pair();
+
+        high ^ low
+    }
+
+    /// Returns a well-mixed 64-bit hash of the UUID for sampling.
+    ///
+    /// Unlike [`Uuid::fingerprint64`], every bit of the result depends on
+    /// every bit of the UUID, so comparing it against a threshold samples
+    /// UUIDs evenly even when they share a timestamp prefix, as in version
+    /// 7. The computation is fixed, so it can be reproduced exactly by
+    /// services written in other languages. It isn't a cryptographic hash.
+    ///
+    /// With `high` and `low` as returned by [`Uuid::as_u64_pair`], and all
+    /// arithmetic on wrapping unsigned 64-bit integers, the hash is
+    /// `mix(high ^ mix(low))`, where `mix` is the SplitMix64 finalizer:
+    ///
+    /// ```text
+    /// mix(z):
+    ///     z = (z ^ (z >> 30)) * 0xbf58476d1ce4e5b9
+    ///     z = (z ^ (z >> 27)) * 0x94d049bb133111eb
+    ///     return z ^ (z >> 31)
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// Sampling roughly 1% of UUIDs:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(0x34203824e5feb163, uuid.sample_hash());
+    ///
+    /// let sampled = uuid.sample_hash() < u64::MAX / 100;
+    /// # let _ = sampled;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn sample_hash(&self) -> u64 {
+        const fn mix(mut z: u64) -> u64 {
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        }
+
+        let (high, low) = self.as_u64_pair();
+
+        mix(high ^ mix(low))
+    }
+
+    /// Returns the number of leading bits that two UUIDs have in common.
+    ///
+    /// The UUIDs are compared as big-endian integers, as in
+    /// [`Uuid::as_u128`], starting from the most significant bit. Two equal
+    /// UUIDs share all 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let a = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    /// let b = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-ffff-ffffffffffff")?;
+    ///
+    /// assert_eq!(66, Uuid::common_prefix_bits(&a, &b));
+    /// assert_eq!(128, Uuid::common_prefix_bits(&a, &a));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn common_prefix_bits(a: &Uuid, b: &Uuid) -> u32 {
+        (a.as_u128() ^ b.as_u128()).leading_zeros()
+    }
+
+    /// Returns the number of leading zero bits in the UUID.
+    ///
+    /// The UUID is treated as a big-endian integer, as in
+    /// [`Uuid::as_u128`]. Combined with XOR, this gives the bucket index of a
+    /// UUID used as a node ID in a Kademlia-style routing table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let a = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    /// let b = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-ffff-ffffffffffff")?;
+    ///
+    /// assert_eq!(66, (a ^ b).leading_zeros());
+    /// assert_eq!(128, Uuid::nil().leading_zeros());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn leading_zeros(&self) -> u32 {
+        self.as_u128().leading_zeros()
+    }
+
+    /// Returns a UUID with the order of its 128 bits reversed.
+    ///
+    /// The UUID is treated as a big-endian integer, as in
+    /// [`Uuid::as_u128`]. Reversing the bits of time-ordered UUIDs, like
+    /// version 7, moves their slowly changing timestamp into the low bits,
+    /// which spreads sequential values evenly when they're used as a shard
+    /// or partition key.
+    ///
+    /// The result is generally not a valid RFC4122 UUID. The transform is
+    /// its own inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!(Uuid::from_u128(1 << 127), Uuid::from_u128(1).reverse_bits());
+    ///
+    /// let uuid = Uuid::from_u128(0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8);
+    /// assert_eq!(uuid, uuid.reverse_bits().reverse_bits());
+    /// ```
+    pub const fn reverse_bits(&self) -> Uuid {
+        Uuid::from_u128(self.as_u128().reverse_bits())
+    }
+
+    /// Returns the absolute difference between two UUIDs as integers.
+    ///
+    /// The UUIDs are compared as big-endian integers, as in
+    /// [`Uuid::as_u128`]. The result is the same regardless of the order of
+    /// the arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let a = Uuid::from_u128(10);
+    /// let b = Uuid::from_u128(250);
+    ///
+    /// assert_eq!(240, Uuid::distance(&a, &b));
+    /// assert_eq!(240, Uuid::distance(&b, &a));
+    /// ```
+    // NOTE: `u128::abs_diff` isn't available on this crate's MSRV
+    #[allow(clippy::manual_abs_diff)]
+    pub const fn distance(a: &Uuid, b: &Uuid) -> u128 {
+        let (a, b) = (a.as_u128(), b.as_u128());
+
+        if a > b {
+            a - b
+        } else {
+            b - a
+        }
+    }
+
+    /// Returns the UUID halfway between two UUIDs as integers.
+    ///
+    /// The UUIDs are treated as big-endian integers, as in
+    /// [`Uuid::as_u128`], and their average is rounded down. The average is
+    /// computed without overflowing, so any pair of UUIDs may be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let a = Uuid::from_u128(10);
+    /// let b = Uuid::from_u128(251);
+    ///
+    /// assert_eq!(Uuid::from_u128(130), Uuid::midpoint(&a, &b));
+    /// ```
+    pub const fn midpoint(a: &Uuid, b: &Uuid) -> Uuid {
+        let (a, b) = (a.as_u128(), b.as_u128());
+
+        Uuid::from_u128((a & b) + ((a ^ b) >> 1))
+    }
+
+    /// Adds `n` to the UUID as an integer, returning `None` if the result
+    /// would be greater than the max UUID.
+    ///
+    /// The UUID is treated as a big-endian integer, as in
+    /// [`Uuid::as_u128`]. The version and variant bits aren't preserved, so
+    /// this is intended for allocating ranges of UUIDs rather than
+    /// generating new ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let base = Uuid::from_u128(0xa1a2a3a4 << 96);
+    ///
+    /// assert_eq!(
+    ///     Some(Uuid::from_u128((0xa1a2a3a4 << 96) + 10)),
+    ///     base.checked_add(10),
+    /// );
+    /// assert_eq!(None, Uuid::from_u128(u128::MAX).checked_add(1));
+    /// ```
+    pub const fn checked_add(&self, n: u128) -> Option<Uuid> {
+        match self.as_u128().checked_add(n) {
+            Some(value) => Some(Uuid::from_u128(value)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `n` from the UUID as an integer, returning `None` if the
+    /// result would be less than the nil UUID.
+    ///
+    /// See [`Uuid::checked_add`] for how the UUID is treated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!(Some(Uuid::nil()), Uuid::from_u128(10).checked_sub(10));
+    /// assert_eq!(None, Uuid::nil().checked_sub(1));
+    /// ```
+    pub const fn checked_sub(&self, n: u128) -> Option<Uuid> {
+        match self.as_u128().checked_sub(n) {
+            Some(value) => Some(Uuid::from_u128(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the 32 nibbles (4-bit values) of this UUID in big-endian order.
+    ///
+    /// This is the inverse of [`Uuid::from_nibbles`]. Each nibble is in the
+    /// range `0..16` and corresponds to one hex digit of the simple format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// let nibbles = uuid.to_nibbles();
+    ///
+    /// assert_eq!([0xa, 0x1, 0xa, 0x2], nibbles[..4]);
+    /// assert_eq!(uuid, Uuid::from_nibbles(&nibbles));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn to_nibbles(&self) -> [u8; 32] {
+        let mut nibbles = [0; 32];
+
+        let mut i = 0;
+        while i < Uuid::BYTES_LEN {
+            nibbles[i * 2] = self.0[i] >> 4;
+            nibbles[i * 2 + 1] = self.0[i] & 0x0f;
+            i += 1;
+        }
+
+        nibbles
+    }
+
+    /// Tests if the UUID is nil (all zeros).
+    pub const fn is_nil(&self) -> bool {
+        self.as_u128() == u128::MIN
+    }
+
+    /// Tests if the UUID is max (all ones).
+    #[cfg(uuid_unstable)]
+    pub const fn is_max(&self) -> bool {
         self.as_u128() == u128::MAX
     }
 
@@ -913,6 +1596,197 @@ impl Uuid {
             _ => None,
         }
     }
+
+    /// If the UUID is the correct version (v1 or v6) this will return the
+    /// node value as a 6-byte array. For other versions this will return
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+    ///
+    /// assert_eq!(Some([0x00, 0xa0, 0xc9, 0x1e, 0x6b, 0xf6]), uuid.get_node_id());
+    ///
+    /// let random = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(None, random.get_node_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn get_node_id(&self) -> Option<[u8; 6]> {
+        match self.get_version() {
+            Some(Version::Mac) => (),
+            #[cfg(uuid_unstable)]
+            Some(Version::SortMac) => (),
+            _ => return None,
+        }
+
+        let bytes = self.as_bytes();
+
+        Some([
+            bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        ])
+    }
+
+    /// If the UUID is the correct version (v1 or v6) this will return whether
+    /// the multicast bit of its node ID is set. For other versions this will
+    /// return `None`.
+    ///
+    /// The multicast bit is the least significant bit of the first node
+    /// octet. It's never set in a hardware MAC address, so RFC4122 uses it to
+    /// mark node IDs that were randomly generated instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mac = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+    ///
+    /// assert_eq!(Some(false), mac.is_multicast_node());
+    ///
+    /// let random = Uuid::parse_str("f81d4fae-7dec-11d0-a765-01a0c91e6bf6")?;
+    ///
+    /// assert_eq!(Some(true), random.is_multicast_node());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn is_multicast_node(&self) -> Option<bool> {
+        match self.get_node_id() {
+            Some(node) => Some(node[0] & 0x01 != 0),
+            None => None,
+        }
+    }
+
+    /// Replaces the node ID of a v1 or v6 UUID with one derived from it
+    /// that doesn't reveal the original.
+    ///
+    /// The node ID of these versions is often the MAC address of the machine
+    /// that generated the UUID. The new node ID is a hash of the original,
+    /// so the same input UUID always anonymizes to the same output, and has
+    /// its multicast bit set to mark it as not being a hardware address. The
+    /// rest of the UUID, including its timestamp, is left intact.
+    ///
+    /// UUIDs of other versions are returned unchanged.
+    ///
+    /// The hash isn't keyed, so this guards against casually exposing MAC
+    /// addresses rather than against a determined attempt to recover them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+    /// let anonymized = uuid.anonymize_node();
+    ///
+    /// assert_ne!(uuid.get_node_id(), anonymized.get_node_id());
+    /// assert_eq!(uuid.get_timestamp(), anonymized.get_timestamp());
+    /// assert_eq!(anonymized, uuid.anonymize_node());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn anonymize_node(&self) -> Uuid {
+        let node = match self.get_node_id() {
+            Some(node) => node,
+            None => return *self,
+        };
+
+        // FNV-1a
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut i = 0;
+
+        while i < node.len() {
+            hash ^= node[i] as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+            i += 1;
+        }
+
+        let hash = hash.to_be_bytes();
+        let mut bytes = self.0;
+
+        // Set the multicast bit in the first octet of the node
+        bytes[10] = hash[2] | 0x01;
+        bytes[11] = hash[3];
+        bytes[12] = hash[4];
+        bytes[13] = hash[5];
+        bytes[14] = hash[6];
+        bytes[15] = hash[7];
+
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Returns a copy of the UUID with its node ID replaced by `node`.
+    ///
+    /// The last six bytes of the UUID are overwritten and the rest, including
+    /// the timestamp and clock sequence of a v1 or v6 UUID, is left intact.
+    /// The version of the UUID isn't checked, so this can be used to craft
+    /// test data from any UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+    /// let node = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    ///
+    /// let other = uuid.with_node(node);
+    ///
+    /// assert_eq!(Some(node), other.get_node_id());
+    /// assert_eq!(uuid.get_timestamp(), other.get_timestamp());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn with_node(&self, node: [u8; 6]) -> Uuid {
+        let mut bytes = self.0;
+
+        bytes[10] = node[0];
+        bytes[11] = node[1];
+        bytes[12] = node[2];
+        bytes[13] = node[3];
+        bytes[14] = node[4];
+        bytes[15] = node[5];
+
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Compares the timestamps embedded in two UUIDs.
+    ///
+    /// Only the timestamp is considered, so the counter, node, or random
+    /// portions of the UUIDs don't affect the result. UUIDs of different
+    /// versions can be compared, since their timestamps are both converted
+    /// to Unix time first.
+    ///
+    /// Returns `None` if either UUID doesn't contain a timestamp. See
+    /// [`Uuid::get_timestamp`] for which versions do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # use std::cmp::Ordering;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let earlier = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")?;
+    /// let later = Uuid::parse_str("f81d4faf-7dec-11d0-8000-000000000000")?;
+    ///
+    /// assert_eq!(Some(Ordering::Less), Uuid::cmp_time(&earlier, &later));
+    ///
+    /// let random = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(None, Uuid::cmp_time(&earlier, &random));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cmp_time(a: &Uuid, b: &Uuid) -> Option<crate::std::cmp::Ordering> {
+        let a = a.get_timestamp()?.to_unix();
+        let b = b.get_timestamp()?.to_unix();
+
+        Some(a.cmp(&b))
+    }
 }
 
 impl Default for Uuid {
@@ -929,6 +1803,60 @@ impl AsRef<[u8]> for Uuid {
     }
 }
 
+impl From<&Bytes> for Uuid {
+    #[inline]
+    fn from(bytes: &Bytes) -> Self {
+        Uuid::from_bytes(*bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl convert::TryFrom<&std::vec::Vec<u8>> for Uuid {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(bytes: &std::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        Uuid::from_slice(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl convert::TryFrom<std::boxed::Box<[u8]>> for Uuid {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(bytes: std::boxed::Box<[u8]>) -> Result<Self, Self::Error> {
+        Uuid::from_slice(&bytes)
+    }
+}
+
+impl ops::BitXor for Uuid {
+    type Output = Uuid;
+
+    #[inline]
+    fn bitxor(self, rhs: Uuid) -> Uuid {
+        Uuid::from_u128(self.as_u128() ^ rhs.as_u128())
+    }
+}
+
+impl ops::BitAnd for Uuid {
+    type Output = Uuid;
+
+    #[inline]
+    fn bitand(self, rhs: Uuid) -> Uuid {
+        Uuid::from_u128(self.as_u128() & rhs.as_u128())
+    }
+}
+
+impl ops::BitOr for Uuid {
+    type Output = Uuid;
+
+    #[inline]
+    fn bitor(self, rhs: Uuid) -> Uuid {
+        Uuid::from_u128(self.as_u128() | rhs.as_u128())
+    }
+}
+
 #[cfg(feature = "serde")]
 pub mod serde {
     //! Adapters for alternative `serde` formats.
@@ -937,7 +1865,7 @@ pub mod serde {
     //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
     //! and deserialized.
 
-    pub use crate::external::serde_support::compact;
+    pub use crate::external::serde_support::{compact, compact_le, nil_as_null};
 }
 
 #[cfg(test)]
@@ -1006,7 +1934,7 @@ mod tests {
         assert_eq!(s, uuid.hyphenated().to_string());
 
         check!(buffer, "{}", uuid, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
     }
 
@@ -1019,7 +1947,7 @@ mod tests {
         let uuid = new();
 
         check!(buffer, "{:x}", uuid, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
     }
 
@@ -1028,7 +1956,7 @@ mod tests {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_uuid_operator_eq() {
         let uuid1 = new();
-        let uuid1_dup = uuid1.clone();
+        let uuid1_dup = uuid1;
         let uuid2 = new2();
 
         assert!(uuid1 == uuid1);
@@ -1053,7 +1981,7 @@ mod tests {
         assert_eq!(s.len(), 36);
 
         check!(buffer, "{}", s, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
     }
 
@@ -1128,6 +2056,133 @@ mod tests {
         assert_eq!(uuid.get_version_num(), 3);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_version_from_number() {
+        for n in 0..=u8::MAX {
+            let version = Version::from_number(n);
+
+            if n < 16 && n != 0 {
+                let mut bytes = new().into_bytes();
+                bytes[6] = (bytes[6] & 0x0f) | (n << 4);
+
+                assert_eq!(Uuid::from_bytes(bytes).get_version(), version, "{}", n);
+            }
+
+            if let Some(version) = version {
+                assert!(n < 16, "{}", n);
+
+                if version != Version::Nil {
+                    let uuid = Builder::from_bytes(new().into_bytes())
+                        .with_version(version)
+                        .into_uuid();
+
+                    assert_eq!(n as usize, uuid.get_version_num());
+                }
+            }
+        }
+
+        assert_eq!(Some(Version::Nil), Version::from_number(0));
+        assert_eq!(Some(Version::Mac), Version::from_number(1));
+        assert_eq!(Some(Version::Sha1), Version::from_number(5));
+        assert_eq!(None, Version::from_number(16));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_variant_from_byte() {
+        for octet in 0..=u8::MAX {
+            let mut bytes = new().into_bytes();
+            bytes[8] = octet;
+
+            assert_eq!(
+                Uuid::from_bytes(bytes).get_variant(),
+                Variant::from_byte(octet)
+            );
+        }
+
+        for &variant in &[
+            Variant::NCS,
+            Variant::RFC4122,
+            Variant::Microsoft,
+            Variant::Future,
+        ] {
+            let uuid = Builder::from_bytes(new().into_bytes())
+                .with_variant(variant)
+                .into_uuid();
+
+            assert_eq!(variant, Variant::from_byte(uuid.as_bytes()[8]));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_version() {
+        let uuid = new();
+
+        assert!(uuid.is_version(Version::Random));
+        assert!(!uuid.is_version(Version::Mac));
+        assert!(Uuid::nil().is_version(Version::Nil));
+        assert!(!new().is_version(Version::Nil));
+
+        // Unknown versions don't match anything
+        let mut bytes = [0xff; 16];
+        bytes[6] = 0xe0;
+
+        let unknown = Uuid::from_bytes(bytes);
+        assert!(!unknown.is_any_version(&[
+            Version::Nil,
+            Version::Mac,
+            Version::Dce,
+            Version::Md5,
+            Version::Random,
+            Version::Sha1,
+        ]));
+
+        assert!(uuid.is_any_version(&[Version::Mac, Version::Random]));
+        assert!(!uuid.is_any_version(&[Version::Mac, Version::Sha1]));
+        assert!(!uuid.is_any_version(&[]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_time_ordered() {
+        let v6 = Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9e6bdeced846").unwrap();
+        let v7 = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+        let v1 = Uuid::parse_str("a1a2a3a4-b1b2-11c2-8000-010203040506").unwrap();
+
+        assert!(v6.is_time_ordered());
+        assert!(v7.is_time_ordered());
+        assert!(!v1.is_time_ordered());
+        assert!(!new().is_time_ordered());
+        assert!(!Uuid::nil().is_time_ordered());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_version_histogram() {
+        assert_eq!([0; 16], Uuid::version_histogram(None));
+
+        let uuids = (0..16u8).flat_map(|version| {
+            let mut bytes = [0xff; 16];
+            bytes[6] = (version << 4) | 0x0f;
+
+            (0..=version).map(move |_| Uuid::from_bytes(bytes))
+        });
+
+        let histogram = Uuid::version_histogram(uuids);
+
+        for (version, count) in histogram.iter().enumerate() {
+            assert_eq!(version as u32 + 1, *count);
+        }
+
+        let histogram = Uuid::version_histogram(vec![Uuid::nil(), new(), new2(), new()]);
+
+        assert_eq!(1, histogram[0]);
+        assert_eq!(3, histogram[4]);
+        assert_eq!(4, histogram.iter().sum::<u32>());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_get_variant() {
@@ -1138,12 +2193,67 @@ mod tests {
         let uuid5 = Uuid::parse_str("F9168C5E-CEB2-4faa-D6BF-329BF39FA1E4").unwrap();
         let uuid6 = Uuid::parse_str("f81d4fae-7dec-11d0-7765-00a0c91e6bf6").unwrap();
 
-        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
-        assert_eq!(uuid2.get_variant(), Variant::RFC4122);
-        assert_eq!(uuid3.get_variant(), Variant::RFC4122);
-        assert_eq!(uuid4.get_variant(), Variant::Microsoft);
-        assert_eq!(uuid5.get_variant(), Variant::Microsoft);
-        assert_eq!(uuid6.get_variant(), Variant::NCS);
+        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid2.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid3.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid4.get_variant(), Variant::Microsoft);
+        assert_eq!(uuid5.get_variant(), Variant::Microsoft);
+        assert_eq!(uuid6.get_variant(), Variant::NCS);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_variant_bit_patterns() {
+        for octet in 0..=u8::MAX {
+            let mut bytes = [0; 16];
+            bytes[8] = octet;
+
+            let expected = match octet >> 5 {
+                0b000..=0b011 => Variant::NCS,
+                0b100..=0b101 => Variant::RFC4122,
+                0b110 => Variant::Microsoft,
+                _ => Variant::Future,
+            };
+
+            assert_eq!(
+                expected,
+                Uuid::from_bytes(bytes).get_variant(),
+                "{:#x}",
+                octet
+            );
+        }
+
+        assert_eq!(
+            Variant::Microsoft,
+            Uuid::from_u128(0xc000_0000_0000_0000_u128).get_variant()
+        );
+        assert_eq!(
+            Variant::Microsoft,
+            Uuid::from_u128(0xdfff_ffff_ffff_ffff_u128).get_variant()
+        );
+        assert_eq!(
+            Variant::Future,
+            Uuid::from_u128(0xe000_0000_0000_0000_u128).get_variant()
+        );
+        assert_eq!(
+            Variant::Future,
+            Uuid::from_u128(0xffff_ffff_ffff_ffff_u128).get_variant()
+        );
+
+        for variant in [
+            Variant::NCS,
+            Variant::RFC4122,
+            Variant::Microsoft,
+            Variant::Future,
+        ] {
+            for fill in [0x00, 0xff] {
+                let uuid = Builder::from_bytes([fill; 16])
+                    .with_variant(variant)
+                    .into_uuid();
+
+                assert_eq!(variant, uuid.get_variant());
+            }
+        }
     }
 
     #[test]
@@ -1153,7 +2263,7 @@ mod tests {
         let s = uuid1.simple().to_string();
 
         assert_eq!(s.len(), 32);
-        assert!(s.chars().all(|c| c.is_digit(16)));
+        assert!(s.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
     #[test]
@@ -1163,7 +2273,7 @@ mod tests {
         let s = uuid1.hyphenated().to_string();
 
         assert_eq!(36, s.len());
-        assert!(s.chars().all(|c| c.is_digit(16) || c == '-'));
+        assert!(s.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
     }
 
     #[test]
@@ -1184,35 +2294,35 @@ mod tests {
         }
 
         check!(buf, "{:x}", u, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
         check!(buf, "{:X}", u, 36, |c| c.is_uppercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
-        check!(buf, "{:#x}", u, 36, |c| c.is_lowercase() || c.is_digit(10) || c == '-');
-        check!(buf, "{:#X}", u, 36, |c| c.is_uppercase() || c.is_digit(10) || c == '-');
+        check!(buf, "{:#x}", u, 36, |c| c.is_lowercase() || c.is_ascii_digit() || c == '-');
+        check!(buf, "{:#X}", u, 36, |c| c.is_uppercase() || c.is_ascii_digit() || c == '-');
 
         check!(buf, "{:X}", u.hyphenated(), 36, |c| c.is_uppercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
         check!(buf, "{:X}", u.simple(), 32, |c| c.is_uppercase()
-            || c.is_digit(10));
+            || c.is_ascii_digit());
         check!(buf, "{:#X}", u.hyphenated(), 36, |c| c.is_uppercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
         check!(buf, "{:#X}", u.simple(), 32, |c| c.is_uppercase()
-            || c.is_digit(10));
+            || c.is_ascii_digit());
 
         check!(buf, "{:x}", u.hyphenated(), 36, |c| c.is_lowercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
         check!(buf, "{:x}", u.simple(), 32, |c| c.is_lowercase()
-            || c.is_digit(10));
+            || c.is_ascii_digit());
         check!(buf, "{:#x}", u.hyphenated(), 36, |c| c.is_lowercase()
-            || c.is_digit(10)
+            || c.is_ascii_digit()
             || c == '-');
         check!(buf, "{:#x}", u.simple(), 32, |c| c.is_lowercase()
-            || c.is_digit(10));
+            || c.is_ascii_digit());
     }
 
     #[test]
@@ -1224,7 +2334,7 @@ mod tests {
 
         assert!(ss.starts_with("urn:uuid:"));
         assert_eq!(s.len(), 36);
-        assert!(s.chars().all(|c| c.is_digit(16) || c == '-'));
+        assert!(s.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
     }
 
     #[test]
@@ -1284,6 +2394,66 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_fields_owned() {
+        const U: Uuid = Uuid::from_fields_owned(
+            0xa1a2a3a4,
+            0xb1b2,
+            0xc1c2,
+            [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8],
+        );
+
+        assert_eq!(
+            (
+                0xa1a2a3a4,
+                0xb1b2,
+                0xc1c2,
+                &[0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8]
+            ),
+            U.as_fields()
+        );
+
+        // The node field is stored in the order given
+        assert_eq!(
+            &[0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8],
+            &U.as_bytes()[8..]
+        );
+        assert_eq!(
+            &[0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8],
+            U.to_fields_le().3
+        );
+
+        let u = new();
+        let (d1, d2, d3, d4) = u.as_fields();
+        assert_eq!(u, Uuid::from_fields_owned(d1, d2, d3, *d4));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_fields_slice() {
+        use crate::error::ErrorKind;
+
+        let d1: u32 = 0xa1a2a3a4;
+        let d2: u16 = 0xb1b2;
+        let d3: u16 = 0xc1c2;
+        let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9];
+
+        let u = Uuid::from_fields_slice(d1, d2, d3, &d4[..8]).unwrap();
+
+        let expected = "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8";
+        assert_eq!(u.simple().to_string(), expected);
+
+        assert_eq!(
+            Err(Error(ErrorKind::FieldLength { len: 9 })),
+            Uuid::from_fields_slice(d1, d2, d3, &d4)
+        );
+        assert_eq!(
+            Err(Error(ErrorKind::FieldLength { len: 0 })),
+            Uuid::from_fields_slice(d1, d2, d3, &[])
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_as_fields() {
@@ -1420,6 +2590,17 @@ mod tests {
         assert_eq!(low_in, low_out);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_clock_seq_and_node() {
+        let node = [0x00, 0xa0, 0xc9, 0x1e, 0x6b, 0xf6];
+        let v1 = Builder::from_rfc4122_timestamp(1, 0x2765, &node).into_uuid();
+
+        assert_eq!(0xa76500a0c91e6bf6, v1.clock_seq_and_node());
+        assert_eq!(new().as_u64_pair().1, new().clock_seq_and_node());
+        assert_eq!(0, Uuid::nil().clock_seq_and_node());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_u128_le_is_actually_le() {
@@ -1431,6 +2612,41 @@ mod tests {
         assert_eq!(v_in, v_out.swap_bytes());
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_byte() {
+        const U: Uuid = Uuid::from_u128(0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8);
+        const FIRST: u8 = U.byte(0);
+        const LAST: u8 = U.as_bytes()[Uuid::BYTES_LEN - 1];
+
+        assert_eq!(0xa1, FIRST);
+        assert_eq!(0xd8, LAST);
+
+        let u = new();
+        for i in 0..Uuid::BYTES_LEN {
+            assert_eq!(u.as_bytes()[i], u.byte(i));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[should_panic]
+    fn test_byte_out_of_bounds() {
+        let _ = new().byte(Uuid::BYTES_LEN);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_bytes_len() {
+        assert_eq!(Uuid::BYTES_LEN, crate::std::mem::size_of::<Uuid>());
+        assert_eq!(Uuid::BYTES_LEN, crate::std::mem::size_of::<Bytes>());
+        assert_eq!(Uuid::BYTES_LEN, new().as_bytes().len());
+
+        let buf = [0; Uuid::BYTES_LEN + 1];
+        assert_eq!(Ok(Uuid::nil()), Uuid::from_slice(&buf[..Uuid::BYTES_LEN]));
+        assert!(Uuid::from_slice(&buf).is_err());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_from_slice() {
@@ -1445,6 +2661,35 @@ mod tests {
         assert_eq!(u.simple().to_string(), expected);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_array_ref() {
+        let u = new();
+
+        assert_eq!(u, Uuid::from(u.as_bytes()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_from_containers() {
+        use crate::std::{boxed::Box, convert::TryInto, vec::Vec};
+
+        let u = new();
+        let v: Vec<u8> = u.as_bytes().to_vec();
+        let b: Box<[u8]> = v.clone().into_boxed_slice();
+
+        assert_eq!(u, (&v).try_into().unwrap());
+        assert_eq!(u, b.try_into().unwrap());
+
+        let short: Vec<u8> = v[..15].to_vec();
+        let err: Error = TryInto::<Uuid>::try_into(&short).unwrap_err();
+        assert_eq!(err, Uuid::from_slice(&short).unwrap_err());
+
+        let long: Box<[u8]> = [0; 17].to_vec().into_boxed_slice();
+        assert!(TryInto::<Uuid>::try_into(long).is_err());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_from_bytes() {
@@ -1504,13 +2749,409 @@ mod tests {
         assert_eq!(u1, u2);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_swap_bytes() {
+        let u = new();
+
+        let swapped = u.swap_bytes();
+
+        assert_ne!(u, swapped);
+        assert_eq!(u, swapped.swap_bytes());
+        assert_eq!(u.to_u128_le(), swapped.as_u128());
+        assert_eq!(Uuid::from_u128_le(u.as_u128()), swapped);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_pg_copy_bytes() {
+        let u = new();
+
+        assert_eq!(u.as_bytes(), &u.to_pg_copy_bytes());
+        assert_eq!(u.as_u128().to_be_bytes(), u.to_pg_copy_bytes());
+        assert_eq!([0; 16], Uuid::nil().to_pg_copy_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_ipv6() {
+        let u = new();
+
+        let addr = u.to_ipv6();
+
+        assert_eq!(u.as_bytes(), &addr.octets());
+        assert_eq!(u, Uuid::from_ipv6(addr));
+        assert_eq!(std::net::Ipv6Addr::UNSPECIFIED, Uuid::nil().to_ipv6());
+        assert_eq!(
+            "f916:8c5e:ceb2:4faa:b6bf:329b:f39f:a1e4".parse(),
+            Ok(u.to_ipv6())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_bit_ops() {
+        let a = new();
+        let b = new2();
+
+        assert_eq!(a.as_u128() ^ b.as_u128(), (a ^ b).as_u128());
+        assert_eq!(a.as_u128() & b.as_u128(), (a & b).as_u128());
+        assert_eq!(a.as_u128() | b.as_u128(), (a | b).as_u128());
+
+        assert_eq!(Uuid::nil(), a ^ a);
+        assert_eq!(a, a ^ Uuid::nil());
+        assert_eq!(a, a & a);
+        assert_eq!(Uuid::nil(), a & Uuid::nil());
+        assert_eq!(a, a | Uuid::nil());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_capnp() {
+        let u = new();
+
+        let (hi, lo) = u.to_capnp();
+
+        assert_eq!(0xF9168C5ECEB24FAA, hi);
+        assert_eq!(0xB6BF329BF39FA1E4, lo);
+        assert_eq!(u, Uuid::from_capnp(hi, lo));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_split_join_high32() {
+        let u = new();
+
+        let (hi, rest) = u.split_high32();
+
+        assert_eq!(0xF9168C5E, hi);
+        assert_eq!(u.as_bytes()[4..], rest);
+        assert_eq!(u.as_fields().0, hi);
+        assert_eq!(u, Uuid::join_high32(hi, rest));
+
+        assert_eq!((0, [0; 12]), Uuid::nil().split_high32());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_node_id() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let v1 = Builder::from_rfc4122_timestamp(1, 0, &node).into_uuid();
+
+        assert_eq!(Some(node), v1.get_node_id());
+        assert_eq!(None, new().get_node_id());
+        assert_eq!(None, Uuid::nil().get_node_id());
+
+        #[cfg(uuid_unstable)]
+        {
+            let v6 = Builder::from_sorted_rfc4122_timestamp(1, 0, &node).into_uuid();
+
+            assert_eq!(Some(node), v6.get_node_id());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_multicast_node() {
+        let mac = Builder::from_rfc4122_timestamp(1, 0, &[0x02, 0, 0, 0, 0, 0x01]).into_uuid();
+        let random = Builder::from_rfc4122_timestamp(1, 0, &[0x03, 0, 0, 0, 0, 0]).into_uuid();
+
+        assert_eq!(Some(false), mac.is_multicast_node());
+        assert_eq!(Some(true), random.is_multicast_node());
+        assert_eq!(Some(true), mac.anonymize_node().is_multicast_node());
+
+        assert_eq!(None, new().is_multicast_node());
+        assert_eq!(None, Uuid::nil().is_multicast_node());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_anonymize_node() {
+        let node = [0x00, 0xa0, 0xc9, 0x1e, 0x6b, 0xf6];
+
+        let v1 = Builder::from_rfc4122_timestamp(1, 2, &node).into_uuid();
+        let anonymized = v1.anonymize_node();
+
+        let anonymized_node = anonymized.get_node_id().unwrap();
+
+        assert_ne!(node, anonymized_node);
+        assert_eq!(0x01, anonymized_node[0] & 0x01);
+        assert_eq!(v1.as_bytes()[..10], anonymized.as_bytes()[..10]);
+        assert_eq!(anonymized, v1.anonymize_node());
+
+        let other = Builder::from_rfc4122_timestamp(1, 2, &[0xff; 6]).into_uuid();
+
+        assert_ne!(
+            anonymized.get_node_id(),
+            other.anonymize_node().get_node_id()
+        );
+
+        assert_eq!(new(), new().anonymize_node());
+        assert_eq!(Uuid::nil(), Uuid::nil().anonymize_node());
+
+        #[cfg(uuid_unstable)]
+        {
+            let v6 = Builder::from_sorted_rfc4122_timestamp(1, 2, &node).into_uuid();
+
+            assert_eq!(anonymized_node, v6.anonymize_node().get_node_id().unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_with_node() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let v1 = Builder::from_rfc4122_timestamp(1, 2, &[0xff; 6]).into_uuid();
+        let replaced = v1.with_node(node);
+
+        assert_eq!(Some(node), replaced.get_node_id());
+        assert_eq!(v1.as_bytes()[..10], replaced.as_bytes()[..10]);
+        assert_eq!(v1, replaced.with_node([0xff; 6]));
+
+        // The version isn't checked
+        let random = new().with_node(node);
+
+        assert_eq!(None, random.get_node_id());
+        assert_eq!(node, random.as_bytes()[10..]);
+        assert_eq!(new().as_bytes()[..10], random.as_bytes()[..10]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_cmp_time() {
+        use crate::std::cmp::Ordering;
+
+        let ticks = timestamp::UUID_TICKS_BETWEEN_EPOCHS + 1_000 * 10_000_000;
+
+        let a = Builder::from_rfc4122_timestamp(ticks, 1, &[1, 2, 3, 4, 5, 6]).into_uuid();
+        let b = Builder::from_rfc4122_timestamp(ticks, 2, &[6, 5, 4, 3, 2, 1]).into_uuid();
+        let c = Builder::from_rfc4122_timestamp(ticks + 1, 0, &[0; 6]).into_uuid();
+
+        assert_eq!(Some(Ordering::Equal), Uuid::cmp_time(&a, &b));
+        assert_eq!(Some(Ordering::Less), Uuid::cmp_time(&b, &c));
+        assert_eq!(Some(Ordering::Greater), Uuid::cmp_time(&c, &a));
+
+        assert_eq!(None, Uuid::cmp_time(&a, &new()));
+        assert_eq!(None, Uuid::cmp_time(&Uuid::nil(), &a));
+        assert_eq!(None, Uuid::cmp_time(&new(), &new2()));
+    }
+
+    #[cfg(uuid_unstable)]
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_cmp_time_across_versions() {
+        use crate::std::cmp::Ordering;
+
+        let ticks = timestamp::UUID_TICKS_BETWEEN_EPOCHS + 1_000 * 10_000_000;
+
+        let v1 = Builder::from_rfc4122_timestamp(ticks, 0, &[0; 6]).into_uuid();
+        let v6 = Builder::from_sorted_rfc4122_timestamp(ticks, 0, &[0; 6]).into_uuid();
+        let v7 = Builder::from_unix_timestamp_millis(1_000_000, &[0xff; 10]).into_uuid();
+        let v7_later = Builder::from_unix_timestamp_millis(1_000_001, &[0; 10]).into_uuid();
+
+        assert_eq!(Some(Ordering::Equal), Uuid::cmp_time(&v1, &v6));
+        assert_eq!(Some(Ordering::Equal), Uuid::cmp_time(&v6, &v7));
+        assert_eq!(Some(Ordering::Less), Uuid::cmp_time(&v7, &v7_later));
+        assert_eq!(Some(Ordering::Greater), Uuid::cmp_time(&v7_later, &v1));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_bytes_into() {
+        struct Wrapper(Bytes);
+
+        impl From<Wrapper> for Bytes {
+            fn from(wrapper: Wrapper) -> Self {
+                wrapper.0
+            }
+        }
+
+        let u = new();
+
+        assert_eq!(u, Uuid::from_bytes_into(Wrapper(u.into_bytes())));
+        assert_eq!(u, Uuid::from_bytes_into(u.into_bytes()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_sample_hash() {
+        // These are fixed by the documented algorithm and must never change
+        assert_eq!(0, Uuid::nil().sample_hash());
+        assert_eq!(0x985b8d4a23d6f257, new().sample_hash());
+        assert_eq!(0xd6bdf7544574c9cb, Uuid::from_u128(u128::MAX).sample_hash());
+
+        assert_ne!(new().sample_hash(), new2().sample_hash());
+        assert_ne!(
+            new().sample_hash(),
+            Uuid::from_u64_pair(new().as_u64_pair().1, new().as_u64_pair().0).sample_hash()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_fingerprint64() {
+        assert_eq!(0, Uuid::nil().fingerprint64());
+        assert_eq!(
+            0xf9168c5eceb24faa ^ 0xb6bf329bf39fa1e4,
+            new().fingerprint64()
+        );
+
+        // `new` and `new2` only differ in their high halves
+        assert_eq!(1, new().fingerprint64() ^ new2().fingerprint64());
+
+        let (high, low) = new().as_u64_pair();
+        assert_eq!(
+            new().fingerprint64(),
+            Uuid::from_u64_pair(low, high).fingerprint64()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_common_prefix_bits() {
+        let u = new();
+
+        assert_eq!(128, Uuid::common_prefix_bits(&u, &u));
+        assert_eq!(
+            0,
+            Uuid::common_prefix_bits(&Uuid::nil(), &Uuid::from_u128(1 << 127))
+        );
+        assert_eq!(
+            127,
+            Uuid::common_prefix_bits(&Uuid::nil(), &Uuid::from_u128(1))
+        );
+
+        for bit in 0..128 {
+            let flipped = Uuid::from_u128(u.as_u128() ^ (1 << bit));
+
+            assert_eq!(127 - bit, Uuid::common_prefix_bits(&u, &flipped));
+            assert_eq!(127 - bit, Uuid::common_prefix_bits(&flipped, &u));
+        }
+
+        // `new` and `new2` only differ in the last bit of their 8th byte
+        assert_eq!(63, Uuid::common_prefix_bits(&new(), &new2()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_leading_zeros() {
+        assert_eq!(128, Uuid::nil().leading_zeros());
+        assert_eq!(0, new().leading_zeros());
+        assert_eq!(127, Uuid::from_u128(1).leading_zeros());
+        assert_eq!(63, (new() ^ new2()).leading_zeros());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_reverse_bits() {
+        assert_eq!(Uuid::nil(), Uuid::nil().reverse_bits());
+        assert_eq!(Uuid::from_u128(1), Uuid::from_u128(1 << 127).reverse_bits());
+        assert_eq!(new(), new().reverse_bits().reverse_bits());
+        assert_eq!(
+            new().as_u128().reverse_bits(),
+            new().reverse_bits().as_u128()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_checked_add_and_sub() {
+        let max = Uuid::from_u128(u128::MAX);
+
+        assert_eq!(Some(new()), new().checked_add(0));
+        assert_eq!(Some(new2()), new().checked_add(1 << 64));
+        assert_eq!(Some(new()), new2().checked_sub(1 << 64));
+        assert_eq!(Some(max), Uuid::nil().checked_add(u128::MAX));
+        assert_eq!(Some(Uuid::nil()), max.checked_sub(u128::MAX));
+
+        assert_eq!(None, max.checked_add(1));
+        assert_eq!(None, new().checked_add(u128::MAX));
+        assert_eq!(None, Uuid::nil().checked_sub(1));
+        assert_eq!(None, new().checked_sub(u128::MAX));
+
+        let a = new();
+        let n = 0x1234_5678;
+        assert_eq!(Some(a), a.checked_add(n).and_then(|b| b.checked_sub(n)));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_distance_and_midpoint() {
+        let max = Uuid::from_u128(u128::MAX);
+
+        assert_eq!(0, Uuid::distance(&new(), &new()));
+        assert_eq!(1 << 64, Uuid::distance(&new(), &new2()));
+        assert_eq!(u128::MAX, Uuid::distance(&Uuid::nil(), &max));
+        assert_eq!(u128::MAX, Uuid::distance(&max, &Uuid::nil()));
+
+        assert_eq!(new(), Uuid::midpoint(&new(), &new()));
+        assert_eq!(max, Uuid::midpoint(&max, &max));
+        assert_eq!(
+            Uuid::from_u128(u128::MAX / 2),
+            Uuid::midpoint(&Uuid::nil(), &max)
+        );
+        assert_eq!(
+            Uuid::from_u128(u128::MAX - 1),
+            Uuid::midpoint(&Uuid::from_u128(u128::MAX - 2), &max)
+        );
+
+        for &(a, b) in &[
+            (0u128, 1u128),
+            (3, 8),
+            (7, 7),
+            (1 << 100, 5),
+            (u128::MAX, 1),
+        ] {
+            let (ua, ub) = (Uuid::from_u128(a), Uuid::from_u128(b));
+            let mid = Uuid::midpoint(&ua, &ub);
+
+            assert_eq!(mid, Uuid::midpoint(&ub, &ua));
+            assert_eq!(Uuid::from_u128(a / 2 + b / 2 + (a & b & 1)), mid);
+            assert!(Uuid::distance(&ua, &mid) <= Uuid::distance(&ua, &ub));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_nibbles_roundtrip() {
+        let u = new();
+
+        let nibbles = u.to_nibbles();
+
+        assert!(nibbles.iter().all(|n| *n < 16));
+        assert_eq!(u, Uuid::from_nibbles(&nibbles));
+
+        let hex = u.simple().to_string();
+        for (nibble, c) in nibbles.iter().zip(hex.chars()) {
+            assert_eq!(c.to_digit(16), Some(*nibble as u32));
+        }
+
+        assert_eq!(Uuid::nil(), Uuid::from_nibbles(&[0; 32]));
+        assert_eq!(Uuid::from_bytes([0xff; 16]), Uuid::from_nibbles(&[0xf; 32]));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic)]
+    fn test_from_nibbles_out_of_range() {
+        let mut nibbles = [0; 32];
+        nibbles[3] = 0x1f;
+
+        // In release builds the high bits of the nibble are discarded
+        let uuid = Uuid::from_nibbles(&nibbles);
+
+        assert_eq!(0x0f, uuid.as_bytes()[1]);
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_iterbytes_impl_for_uuid() {
         let mut set = std::collections::HashSet::new();
         let id1 = new();
         let id2 = new2();
-        set.insert(id1.clone());
+        set.insert(id1);
 
         assert!(set.contains(&id1));
         assert!(!set.contains(&id2));
@@ -0,0 +1,118 @@
+use crate::{
+    error::ErrorKind,
+    std::{convert::TryFrom, fmt, num::NonZeroU128},
+    Error, Uuid,
+};
+
+/// A UUID that's guaranteed not to be nil.
+///
+/// The nil UUID is reserved as the value that represents `None`, so
+/// `Option<NonNilUuid>` is the same size as a `Uuid`. This is useful for
+/// storing many optional UUIDs where the nil UUID isn't a meaningful value
+/// anyway.
+///
+/// `Uuid` itself can't have this niche, because every bit pattern of its
+/// underlying [`Bytes`] is a valid UUID.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{NonNilUuid, Uuid};
+/// # use std::mem::size_of;
+/// # fn main() -> Result<(), uuid::Error> {
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+/// let non_nil = NonNilUuid::new(uuid).unwrap();
+///
+/// assert_eq!(uuid, non_nil.get());
+/// assert_eq!(None, NonNilUuid::new(Uuid::nil()));
+///
+/// assert_eq!(size_of::<Uuid>(), size_of::<Option<NonNilUuid>>());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Bytes`]: type.Bytes.html
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct NonNilUuid(NonZeroU128);
+
+impl NonNilUuid {
+    /// Creates a non-nil UUID, returning `None` if `uuid` is nil.
+    pub const fn new(uuid: Uuid) -> Option<Self> {
+        match NonZeroU128::new(uuid.as_u128()) {
+            Some(value) => Some(NonNilUuid(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the UUID.
+    pub const fn get(self) -> Uuid {
+        Uuid::from_u128(self.0.get())
+    }
+}
+
+impl fmt::Debug for NonNilUuid {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl fmt::Display for NonNilUuid {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+impl From<NonNilUuid> for Uuid {
+    #[inline]
+    fn from(uuid: NonNilUuid) -> Self {
+        uuid.get()
+    }
+}
+
+impl TryFrom<Uuid> for NonNilUuid {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        NonNilUuid::new(uuid).ok_or(Error(ErrorKind::Nil))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::{mem, string::ToString};
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_size() {
+        assert_eq!(mem::size_of::<Uuid>(), mem::size_of::<NonNilUuid>());
+        assert_eq!(mem::size_of::<Uuid>(), mem::size_of::<Option<NonNilUuid>>());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new() {
+        let uuid = Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+        let non_nil = NonNilUuid::new(uuid).unwrap();
+
+        assert_eq!(uuid, non_nil.get());
+        assert_eq!(uuid, Uuid::from(non_nil));
+        assert_eq!(uuid.to_string(), non_nil.to_string());
+        assert_eq!(format!("{:?}", uuid), format!("{:?}", non_nil));
+
+        assert_eq!(None, NonNilUuid::new(Uuid::nil()));
+        assert_eq!(Some(non_nil), NonNilUuid::try_from(uuid).ok());
+        assert_eq!(
+            "the UUID is nil",
+            NonNilUuid::try_from(Uuid::nil()).unwrap_err().to_string()
+        );
+    }
+}
@@ -15,7 +15,8 @@
 
 use crate::{
     error::*,
-    std::{convert::TryFrom, str},
+    fmt::{Braced, Format, FormattedUuid, Hyphenated, Repr, Simple, Urn},
+    std::{convert::TryFrom, ops::Range, str},
     Uuid,
 };
 
@@ -27,6 +28,16 @@ impl str::FromStr for Uuid {
     }
 }
 
+impl str::FromStr for FormattedUuid {
+    type Err = Error;
+
+    fn from_str(uuid_str: &str) -> Result<Self, Self::Err> {
+        let (uuid, format) = Uuid::parse_str_with_format(uuid_str)?;
+
+        Ok(FormattedUuid::new(uuid, format))
+    }
+}
+
 impl TryFrom<&'_ str> for Uuid {
     type Error = Error;
 
@@ -35,12 +46,49 @@ impl TryFrom<&'_ str> for Uuid {
     }
 }
 
+/// The byte range of one group in a hyphenated [`Uuid`] string.
+///
+/// This is returned by [`Uuid::parse_groups`].
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::parse_groups`]: struct.Uuid.html#method.parse_groups
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GroupSpan {
+    start: usize,
+    end: usize,
+}
+
+impl GroupSpan {
+    /// The byte offset of the first character of the group.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte offset just past the last character of the group.
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// The byte range of the group, for slicing the input string.
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
 impl Uuid {
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
     /// hyphens.
     ///
     /// Any of the formats generated by this module (simple, hyphenated, urn,
-    /// Microsoft GUID) are supported by this parsing function.
+    /// Microsoft GUID) are supported by this parsing function. Hyphenated
+    /// UUIDs wrapped in parentheses, like the `P` format of .NET's
+    /// `Guid.ToString`, and URNs wrapped in braces, like
+    /// `{urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8}`, are also
+    /// supported.
+    ///
+    /// Any input that isn't the length of a supported format is immediately
+    /// rejected with a length error, without looking for invalid characters
+    /// or groups first.
     ///
     /// Prefer [`try_parse`] unless you need detailed user-facing diagnostics.
     /// This method will be eventually deprecated in favor of `try_parse`.
@@ -121,6 +169,28 @@ impl Uuid {
     /// # }
     /// ```
     ///
+    /// This method is `const`, so it can also validate a UUID at compile
+    /// time. An invalid input then fails the build instead of failing at
+    /// runtime:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// const ID: Uuid = match Uuid::try_parse_ascii(b"550e8400-e29b-41d4-a716-446655440000") {
+    ///     Ok(uuid) => uuid,
+    ///     Err(_) => panic!("invalid UUID"),
+    /// };
+    ///
+    /// assert_eq!("550e8400-e29b-41d4-a716-446655440000", ID.to_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use uuid::Uuid;
+    /// const ID: Uuid = match Uuid::try_parse_ascii(b"550e8400-e29b-41d4-a716-44665544000g") {
+    ///     Ok(uuid) => uuid,
+    ///     Err(_) => panic!("invalid UUID"),
+    /// };
+    /// ```
+    ///
     /// [`try_parse`]: #method.try_parse
     pub const fn try_parse_ascii(input: &[u8]) -> Result<Uuid, Error> {
         match try_parse(input) {
@@ -130,141 +200,819 @@ impl Uuid {
             Err(_) => Err(Error(ErrorKind::Other)),
         }
     }
-}
-
-const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
-    let result = match (input.len(), input) {
-        // Inputs of 32 bytes must be a non-hyphenated UUID
-        (32, s) => parse_simple(s),
-        // Hyphenated UUIDs may be wrapped in various ways:
-        // - `{UUID}` for braced UUIDs
-        // - `urn:uuid:UUID` for URNs
-        // - `UUID` for a regular hyphenated UUID
-        (36, s)
-        | (38, [b'{', s @ .., b'}'])
-        | (45, [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..]) => {
-            parse_hyphenated(s)
-        }
-        // Any other shaped input is immediately invalid
-        _ => Err(()),
-    };
 
-    match result {
-        Ok(b) => Ok(b),
-        Err(()) => Err(InvalidUuid(input)),
-    }
-}
+    /// Parses a `Uuid` from a string of hexadecimal digits with optional
+    /// hyphens, also returning the format of the input.
+    ///
+    /// This accepts the same inputs as [`parse_str`]. The returned [`Format`]
+    /// can be used to write the `Uuid` back out the same way it was given.
+    /// URNs wrapped in braces are returned as [`Format::Urn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Format, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let (uuid, format) =
+    ///     Uuid::parse_str_with_format("{550e8400-e29b-41d4-a716-446655440000}")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?, uuid);
+    /// assert_eq!(Format::Braced, format);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`Format`]: fmt/enum.Format.html
+    /// [`Format::Urn`]: fmt/enum.Format.html#variant.Urn
+    pub fn parse_str_with_format(input: &str) -> Result<(Uuid, Format), Error> {
+        let uuid = Uuid::parse_str(input)?;
+
+        // Only the supported shapes of input can have parsed successfully
+        let format = match (input.len(), input.as_bytes()) {
+            (32, _) => Format::Simple,
+            (36, _) => Format::Hyphenated,
+            (38, [b'{', ..]) => Format::Braced,
+            (38, _) => Format::Parenthesized,
+            _ => Format::Urn,
+        };
 
-#[inline]
-const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
-    // This length check here removes all other bounds
-    // checks in this function
-    if s.len() != 32 {
-        return Err(());
+        Ok((uuid, format))
     }
 
-    let mut buf: [u8; 16] = [0; 16];
-    let mut i = 0;
+    /// Checks whether the hyphenated string of this `Uuid` matches a
+    /// wildcard pattern.
+    ///
+    /// The pattern has the same 5 groups as a hyphenated `Uuid`. A group
+    /// that's just `*` matches anything, and within other groups `?`
+    /// matches any single hex digit. Other characters are hex digits that
+    /// must match exactly, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the pattern doesn't have 5
+    /// groups, if a group other than `*` is the wrong length, or if it
+    /// contains any character other than hex digits and `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert!(uuid.matches_pattern("550e8400-*-*-*-*")?);
+    /// assert!(uuid.matches_pattern("*-*-41D4-a7??-*")?);
+    /// assert!(!uuid.matches_pattern("*-*-*-*-??????????01")?);
+    ///
+    /// assert!(uuid.matches_pattern("550e8400-*").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches_pattern(&self, pattern: &str) -> Result<bool, Error> {
+        const GROUP_LENGTHS: [usize; 5] = [8, 4, 4, 4, 12];
+        const BLOCK_STARTS: [usize; 5] = [0, 9, 14, 19, 24];
 
-    while i < 16 {
-        // Convert a two-char hex value (like `A8`)
-        // into a byte (like `10101000`)
-        let h1 = HEX_TABLE[s[i * 2] as usize];
-        let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
+        let count = pattern.split('-').count();
+        if count != 5 {
+            return Err(Error(ErrorKind::GroupCount { count }));
+        }
 
-        // We use `0xff` as a sentinel value to indicate
-        // an invalid hex character sequence (like the letter `G`)
-        if h1 | h2 == 0xff {
-            return Err(());
+        let hyphenated = self.to_hyphenated_bytes();
+
+        let mut matches = true;
+        let mut start = 0;
+
+        for (group, part) in pattern.split('-').enumerate() {
+            if part != "*" {
+                if part.len() != GROUP_LENGTHS[group] {
+                    return Err(Error(ErrorKind::GroupLength {
+                        group,
+                        len: part.len(),
+                        index: start + 1,
+                    }));
+                }
+
+                // `*` groups can shift the pattern, so index the `Uuid` by
+                // its own group bounds
+                for (i, character) in part.char_indices() {
+                    match character {
+                        '?' => (),
+                        c if c.is_ascii_hexdigit() => {
+                            let expected = hyphenated[BLOCK_STARTS[group] + i];
+
+                            matches &= c.to_ascii_lowercase() as u8 == expected;
+                        }
+                        character => {
+                            return Err(Error(ErrorKind::Char {
+                                character,
+                                index: start + i + 1,
+                            }))
+                        }
+                    }
+                }
+            }
+
+            start += part.len() + 1;
         }
 
-        // The upper nibble needs to be shifted into position
-        // to produce the final byte value
-        buf[i] = SHL4_TABLE[h1 as usize] | h2;
-        i += 1;
+        Ok(matches)
     }
 
-    Ok(buf)
-}
+    /// Finds the byte ranges of the 5 groups of a hyphenated `Uuid` string.
+    ///
+    /// This validates the groups in order, so as a string is typed each
+    /// complete group can be highlighted. Only the plain hyphenated form,
+    /// like `67e55044-10b1-426f-9247-bb680e5fe0c8`, is supported.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error for the first group that contains
+    /// a character other than a hex digit or is the wrong length, or if the
+    /// input doesn't have 5 groups. The error's message includes the group
+    /// and character position it stopped at, and every group before it was
+    /// valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let input = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    /// let groups = Uuid::parse_groups(input)?;
+    ///
+    /// assert_eq!("67e55044", &input[groups[0].range()]);
+    /// assert_eq!("bb680e5fe0c8", &input[groups[4].range()]);
+    ///
+    /// assert!(Uuid::parse_groups("67e55044-10b").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_groups(input: &str) -> Result<[GroupSpan; 5], Error> {
+        const GROUP_LENGTHS: [usize; 5] = [8, 4, 4, 4, 12];
+
+        let mut spans = [GroupSpan { start: 0, end: 0 }; 5];
+        let mut start = 0;
+        let mut count = 0;
+
+        for (group, part) in input.split('-').enumerate() {
+            if group == GROUP_LENGTHS.len() {
+                return Err(Error(ErrorKind::GroupCount {
+                    count: input.split('-').count(),
+                }));
+            }
+
+            for (i, character) in part.char_indices() {
+                if !character.is_ascii_hexdigit() {
+                    return Err(Error(ErrorKind::Char {
+                        character,
+                        index: start + i + 1,
+                    }));
+                }
+            }
+
+            if part.len() != GROUP_LENGTHS[group] {
+                return Err(Error(ErrorKind::GroupLength {
+                    group,
+                    len: part.len(),
+                    index: start + 1,
+                }));
+            }
+
+            spans[group] = GroupSpan {
+                start,
+                end: start + part.len(),
+            };
+
+            start += part.len() + 1;
+            count += 1;
+        }
 
-#[inline]
-const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
-    // This length check here removes all other bounds
-    // checks in this function
-    if s.len() != 36 {
-        return Err(());
+        if count != GROUP_LENGTHS.len() {
+            return Err(Error(ErrorKind::GroupCount { count }));
+        }
+
+        Ok(spans)
     }
 
-    // We look at two hex-encoded values (4 chars) at a time because
-    // that's the size of the smallest group in a hyphenated UUID.
-    // The indexes we're interested in are:
-    //
-    // uuid     : 936da01f-9abd-4d9d-80c7-02af85c822a8
-    //            |   |   ||   ||   ||   ||   |   |
-    // hyphens  : |   |   8|  13|  18|  23|   |   |
-    // positions: 0   4    9   14   19   24  28  32
+    /// Parses a `Uuid` from a string of hexadecimal digits with optional
+    /// hyphens, returning a lightweight error on failure.
+    ///
+    /// This function uses the same parser as [`parse_str`], but its error
+    /// type, [`ParseErrorKind`], only describes what kind of problem was
+    /// found and is `Copy`. This can be convenient for hot validation paths
+    /// that don't need detailed user-facing diagnostics.
+    ///
+    /// Like [`parse_str`], any input that isn't the length of a supported
+    /// format is immediately rejected with [`ParseErrorKind::Length`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{ParseErrorKind, Uuid};
+    /// assert!(Uuid::parse_str_light("550e8400-e29b-41d4-a716-446655440000").is_ok());
+    ///
+    /// assert_eq!(
+    ///     Err(ParseErrorKind::Char),
+    ///     Uuid::parse_str_light("550e8400-e29b-41d4-a716-44665544000g"),
+    /// );
+    /// assert_eq!(
+    ///     Err(ParseErrorKind::Length),
+    ///     Uuid::parse_str_light("550e8400e29b41d4a716"),
+    /// );
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`ParseErrorKind`]: enum.ParseErrorKind.html
+    /// [`ParseErrorKind::Length`]: enum.ParseErrorKind.html#variant.Length
+    pub fn parse_str_light(input: &str) -> Result<Uuid, ParseErrorKind> {
+        try_parse(input.as_bytes())
+            .map(Uuid::from_bytes)
+            .map_err(|err| err.into_err().0.parse_error_kind())
+    }
 
-    // First, ensure the hyphens appear in the right places
-    match [s[8], s[13], s[18], s[23]] {
-        [b'-', b'-', b'-', b'-'] => {}
-        _ => return Err(()),
+    /// Parses a `Uuid` from a string that may be wrapped in quotes.
+    ///
+    /// If `input` starts and ends with the same ASCII quote character, either
+    /// `"` or `'`, then that single layer of quotes is removed before parsing
+    /// with [`parse_str`]. Otherwise `input` is parsed as-is. This is useful
+    /// for values from CSV or JSON exports that were left quoted.
+    ///
+    /// Any error positions refer to the input with its quotes removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse_str_unquoted("\"550e8400-e29b-41d4-a716-446655440000\"")?);
+    /// assert_eq!(uuid, Uuid::parse_str_unquoted("'550e8400-e29b-41d4-a716-446655440000'")?);
+    /// assert_eq!(uuid, Uuid::parse_str_unquoted("550e8400-e29b-41d4-a716-446655440000")?);
+    ///
+    /// // Mismatched quotes aren't removed
+    /// assert!(Uuid::parse_str_unquoted("\"550e8400-e29b-41d4-a716-446655440000'").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_str_unquoted(input: &str) -> Result<Uuid, Error> {
+        Uuid::parse_str(unquote(input, b"\"'"))
     }
 
-    let positions: [u8; 8] = [0, 4, 9, 14, 19, 24, 28, 32];
-    let mut buf: [u8; 16] = [0; 16];
-    let mut j = 0;
+    /// Checks whether `s` is the Postgres text representation of this UUID.
+    ///
+    /// Postgres formats `uuid` values as lower-case hyphenated strings. This
+    /// method compares `s` against that format case-insensitively, after
+    /// removing an optional layer of single quotes, as found in some dumps.
+    /// No allocations are made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert!(uuid.eq_pg_text("550e8400-e29b-41d4-a716-446655440000"));
+    /// assert!(uuid.eq_pg_text("'550E8400-E29B-41D4-A716-446655440000'"));
+    ///
+    /// assert!(!uuid.eq_pg_text("550e8400e29b41d4a716446655440000"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eq_pg_text(&self, s: &str) -> bool {
+        let s = unquote(s, b"'");
 
-    while j < 8 {
-        let i = positions[j];
+        s.eq_ignore_ascii_case(self.hyphenated().encode_lower(&mut Uuid::encode_buffer()))
+    }
 
-        // The decoding here is the same as the simple case
-        // We're just dealing with two values instead of one
-        let h1 = HEX_TABLE[s[i as usize] as usize];
-        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
-        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
-        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+    /// Parses a `Uuid` followed by a `:` and a checksum of its bytes.
+    ///
+    /// The checksum is the sum of the 16 bytes of the UUID, modulo 256,
+    /// written as 2 hex digits in either case. For example, the checksum for
+    /// `550e8400-e29b-41d4-a716-446655440000` is `79`. The UUID itself may be
+    /// in any format supported by [`parse_str`]. Strings in this format can be
+    /// produced by [`to_string_with_checksum`].
+    ///
+    /// # Errors
+    ///
+    /// Along with the errors from [`parse_str`], this method fails if the
+    /// input doesn't end with a checksum, or if the checksum doesn't match
+    /// the UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_with_checksum("550e8400-e29b-41d4-a716-446655440000:79")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?, uuid);
+    ///
+    /// assert!(Uuid::parse_with_checksum("550e8400-e29b-41d4-a716-446655440000:7A").is_err());
+    /// assert!(Uuid::parse_with_checksum("550e8400-e29b-41d4-a716-446655440000").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`to_string_with_checksum`]: #method.to_string_with_checksum
+    pub fn parse_with_checksum(input: &str) -> Result<Uuid, Error> {
+        let (uuid_str, checksum) = match input.rfind(':') {
+            Some(index) if input.len() - index == 3 => (&input[..index], &input[index + 1..]),
+            _ => return Err(Error(ErrorKind::MissingChecksum)),
+        };
 
-        if h1 | h2 | h3 | h4 == 0xff {
-            return Err(());
+        let uuid = Uuid::parse_str(uuid_str)?;
+
+        let mut found = 0;
+        for (i, character) in checksum.char_indices() {
+            match character.to_digit(16) {
+                Some(digit) => found = found << 4 | digit as u8,
+                None => {
+                    return Err(Error(ErrorKind::Char {
+                        character,
+                        index: uuid_str.len() + i + 2,
+                    }))
+                }
+            }
         }
 
-        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
-        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
-        j += 1;
-    }
-
-    Ok(buf)
-}
+        let expected = uuid.checksum();
 
-const HEX_TABLE: &[u8; 256] = &{
-    let mut buf = [0; 256];
-    let mut i: u8 = 0;
+        if found == expected {
+            Ok(uuid)
+        } else {
+            Err(Error(ErrorKind::ChecksumMismatch { expected, found }))
+        }
+    }
 
-    loop {
-        buf[i as usize] = match i {
-            b'0'..=b'9' => i - b'0',
-            b'a'..=b'f' => i - b'a' + 10,
-            b'A'..=b'F' => i - b'A' + 10,
-            _ => 0xff,
-        };
+    /// Parses a `Uuid` from a 22 character shortuuid string.
+    ///
+    /// The shortuuid format, used by the Python `shortuuid` library, encodes
+    /// the UUID as a base57 number using the alphabet
+    /// `23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz`, with the
+    /// most significant digit first.
+    ///
+    /// Also see [`to_shortuuid`] for encoding a `Uuid` in this format.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't 22 characters
+    /// long, contains a character outside of the alphabet, or encodes a
+    /// value that doesn't fit in 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_shortuuid("CXc85b4rqinB7s5J52TRYb")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("3b1f8b40-222c-4a6e-b77e-779d5a94e21c")?, uuid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_shortuuid`]: #method.to_shortuuid
+    pub fn from_shortuuid(input: &str) -> Result<Uuid, Error> {
+        if input.len() != 22 {
+            return Err(Error(ErrorKind::ShortUuidLength { len: input.len() }));
+        }
 
-        if i == 255 {
-            break buf;
+        let mut value: u128 = 0;
+        for (index, character) in input.char_indices() {
+            let digit = match SHORTUUID_ALPHABET
+                .iter()
+                .position(|&b| b as char == character)
+            {
+                Some(digit) => digit as u128,
+                None => {
+                    return Err(Error(ErrorKind::Char {
+                        character,
+                        index: index + 1,
+                    }))
+                }
+            };
+
+            value = match value.checked_mul(57).and_then(|v| v.checked_add(digit)) {
+                Some(value) => value,
+                None => return Err(Error(ErrorKind::ShortUuidOverflow)),
+            };
         }
 
-        i += 1
+        Ok(Uuid::from_u128(value))
     }
-};
 
-const SHL4_TABLE: &[u8; 256] = &{
-    let mut buf = [0; 256];
-    let mut i: u8 = 0;
-
-    loop {
-        buf[i as usize] = i.wrapping_shl(4);
+    /// Parses a `Uuid` from the decimal string of its 128 bit value.
+    ///
+    /// The UUID is treated as an unsigned big-endian integer, as in
+    /// [`Uuid::from_u128`]. This is how some systems, like Java code that
+    /// stores UUIDs as a `BigInteger`, serialize them. Leading zeros are
+    /// allowed.
+    ///
+    /// Also see [`to_decimal_string`] for encoding a `Uuid` in this format.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` is empty, contains any
+    /// character other than the digits `0` to `9`, or encodes a value that
+    /// doesn't fit in 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_decimal_str("113059749145936325402354257176981405696")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?, uuid);
+    ///
+    /// assert!(Uuid::from_decimal_str("340282366920938463463374607431768211456").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to_decimal_string`]: #method.to_decimal_string
+    pub fn from_decimal_str(input: &str) -> Result<Uuid, Error> {
+        if input.is_empty() {
+            return Err(Error(ErrorKind::DecimalEmpty));
+        }
 
-        if i == 255 {
-            break buf;
+        let mut value: u128 = 0;
+        for (index, character) in input.char_indices() {
+            let digit = match character.to_digit(10) {
+                Some(digit) => digit as u128,
+                None => {
+                    return Err(Error(ErrorKind::Char {
+                        character,
+                        index: index + 1,
+                    }))
+                }
+            };
+
+            value = match value.checked_mul(10).and_then(|v| v.checked_add(digit)) {
+                Some(value) => value,
+                None => return Err(Error(ErrorKind::DecimalOverflow)),
+            };
+        }
+
+        Ok(Uuid::from_u128(value))
+    }
+
+    /// Parses a `Uuid` from the 26 character string of a ULID.
+    ///
+    /// ULID strings use Crockford's base32 alphabet, which is the digits and
+    /// the letters except `I`, `L`, `O`, and `U`, ignoring case. The string
+    /// encodes the 128-bit value of the ULID in big-endian order, so the
+    /// result is the same as [`Uuid::from_ulid_bytes`] with the ULID's bytes.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't 26 characters
+    /// long, contains a character outside of the alphabet, or encodes a
+    /// value that doesn't fit in 128 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV")?;
+    ///
+    /// assert_eq!(Uuid::parse_str("01563e3a-b5d3-d676-4c61-efb99302bd5b")?, uuid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_ulid_str(input: &str) -> Result<Uuid, Error> {
+        if input.len() != 26 {
+            return Err(Error(ErrorKind::UlidLength { len: input.len() }));
+        }
+
+        let mut value: u128 = 0;
+        for (index, character) in input.char_indices() {
+            let digit = match CROCKFORD_ALPHABET
+                .iter()
+                .position(|&b| b as char == character.to_ascii_uppercase())
+            {
+                Some(digit) => digit as u128,
+                None => {
+                    return Err(Error(ErrorKind::Char {
+                        character,
+                        index: index + 1,
+                    }))
+                }
+            };
+
+            // 26 characters hold 130 bits, so the first one can't be above `7`
+            if index == 0 && digit > 7 {
+                return Err(Error(ErrorKind::UlidOverflow));
+            }
+
+            value = (value << 5) | digit;
+        }
+
+        Ok(Uuid::from_u128(value))
+    }
+
+    /// Parses a `Uuid` from a string in the given representation.
+    ///
+    /// This dispatches to the parser for `repr`, so callers that read the
+    /// representation from configuration can handle each one the same way.
+    /// See [`Repr`] for the parser used by each representation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the error of the parser for `repr` if
+    /// `input` isn't valid in that representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{fmt::Repr, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?;
+    ///
+    /// assert_eq!(uuid, Uuid::parse("550e8400e29b41d4a716446655440000", Repr::Hex)?);
+    /// assert_eq!(
+    ///     uuid,
+    ///     Uuid::parse("113059749145936325402354257176981405696", Repr::Decimal)?
+    /// );
+    ///
+    /// assert!(Uuid::parse("550e8400-e29b-41d4-a716-446655440000", Repr::Decimal).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Repr`]: fmt/enum.Repr.html
+    pub fn parse(input: &str, repr: Repr) -> Result<Uuid, Error> {
+        match repr {
+            Repr::Hex => Uuid::parse_str(input),
+            Repr::Decimal => Uuid::from_decimal_str(input),
+            Repr::ShortUuid => Uuid::from_shortuuid(input),
+        }
+    }
+
+    /// The checksum used by `parse_with_checksum`.
+    pub(crate) const fn checksum(&self) -> u8 {
+        let mut checksum = 0u8;
+
+        let mut i = 0;
+        while i < Uuid::BYTES_LEN {
+            checksum = checksum.wrapping_add(self.0[i]);
+            i += 1;
+        }
+
+        checksum
+    }
+
+    /// Parses each line of `input` as a `Uuid`.
+    ///
+    /// Lines are separated by either `\n` or `\r\n`, and empty lines are
+    /// skipped. Every other line is parsed with [`parse_str`], so any of its
+    /// supported formats may be used, and mixed, within the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let input = "550e8400-e29b-41d4-a716-446655440000\r\n\n67e5504410b1426f9247bb680e5fe0c8\n";
+    ///
+    /// let uuids = Uuid::parse_lines(input).collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000")?,
+    ///         Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///     ],
+    ///     uuids,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_lines(input: &str) -> impl Iterator<Item = Result<Uuid, Error>> + '_ {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(Uuid::parse_str)
+    }
+}
+
+/// Removes a single layer of matching `quotes` from around `input`.
+pub(crate) fn unquote<'a>(input: &'a str, quotes: &[u8]) -> &'a str {
+    match input.as_bytes() {
+        [open, .., close] if open == close && quotes.contains(open) => &input[1..input.len() - 1],
+        _ => input,
+    }
+}
+
+/// The length of a braced URN, like `{urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+const BRACED_URN_LENGTH: usize = Urn::LENGTH + 2;
+
+/// The lengths of every format accepted by `try_parse`.
+///
+/// Parenthesized UUIDs are the same length as braced ones.
+pub(crate) const SUPPORTED_LENGTHS: [usize; 5] = [
+    Simple::LENGTH,
+    Hyphenated::LENGTH,
+    Braced::LENGTH,
+    Urn::LENGTH,
+    BRACED_URN_LENGTH,
+];
+
+/// Whether `len` is the length of any format accepted by `try_parse`.
+pub(crate) const fn is_supported_len(len: usize) -> bool {
+    let mut i = 0;
+    while i < SUPPORTED_LENGTHS.len() {
+        if SUPPORTED_LENGTHS[i] == len {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
+    // The length is checked before any characters, so inputs that can't be
+    // any supported format are rejected immediately
+    let result = match (input.len(), input) {
+        // Inputs of 32 bytes must be a non-hyphenated UUID
+        (Simple::LENGTH, s) => parse_simple(s),
+        // Hyphenated UUIDs may be wrapped in various ways:
+        // - `{UUID}` for braced UUIDs
+        // - `(UUID)` for parenthesized UUIDs, like the .NET `P` format
+        // - `urn:uuid:UUID` for URNs (the prefix is case-insensitive)
+        // - `{urn:uuid:UUID}` for braced URNs
+        // - `UUID` for a regular hyphenated UUID
+        (Hyphenated::LENGTH, s)
+        | (Braced::LENGTH, [b'{', s @ .., b'}'])
+        | (Braced::LENGTH, [b'(', s @ .., b')'])
+        | (
+            Urn::LENGTH,
+            [
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+            ],
+        )
+        | (
+            BRACED_URN_LENGTH,
+            [
+                b'{',
+                b'u' | b'U',
+                b'r' | b'R',
+                b'n' | b'N',
+                b':',
+                b'u' | b'U',
+                b'u' | b'U',
+                b'i' | b'I',
+                b'd' | b'D',
+                b':',
+                s @ ..,
+                b'}',
+            ],
+        ) => parse_hyphenated(s),
+        // Any other shaped input is immediately invalid
+        _ => Err(()),
+    };
+
+    match result {
+        Ok(b) => Ok(b),
+        Err(()) => Err(InvalidUuid(input)),
+    }
+}
+
+#[inline]
+const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
+    // This length check here removes all other bounds
+    // checks in this function
+    if s.len() != 32 {
+        return Err(());
+    }
+
+    let mut buf = [0; Uuid::BYTES_LEN];
+    let mut i = 0;
+
+    while i < Uuid::BYTES_LEN {
+        // Convert a two-char hex value (like `A8`)
+        // into a byte (like `10101000`)
+        let h1 = HEX_TABLE[s[i * 2] as usize];
+        let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
+
+        // We use `0xff` as a sentinel value to indicate
+        // an invalid hex character sequence (like the letter `G`)
+        if h1 | h2 == 0xff {
+            return Err(());
+        }
+
+        // The upper nibble needs to be shifted into position
+        // to produce the final byte value
+        buf[i] = SHL4_TABLE[h1 as usize] | h2;
+        i += 1;
+    }
+
+    Ok(buf)
+}
+
+#[inline]
+const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
+    // This length check here removes all other bounds
+    // checks in this function
+    if s.len() != 36 {
+        return Err(());
+    }
+
+    // We look at two hex-encoded values (4 chars) at a time because
+    // that's the size of the smallest group in a hyphenated UUID.
+    // The indexes we're interested in are:
+    //
+    // uuid     : 936da01f-9abd-4d9d-80c7-02af85c822a8
+    //            |   |   ||   ||   ||   ||   |   |
+    // hyphens  : |   |   8|  13|  18|  23|   |   |
+    // positions: 0   4    9   14   19   24  28  32
+
+    // First, ensure the hyphens appear in the right places
+    match [s[8], s[13], s[18], s[23]] {
+        [b'-', b'-', b'-', b'-'] => {}
+        _ => return Err(()),
+    }
+
+    let positions: [u8; 8] = [0, 4, 9, 14, 19, 24, 28, 32];
+    let mut buf = [0; Uuid::BYTES_LEN];
+    let mut j = 0;
+
+    while j < 8 {
+        let i = positions[j];
+
+        // The decoding here is the same as the simple case
+        // We're just dealing with two values instead of one
+        let h1 = HEX_TABLE[s[i as usize] as usize];
+        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
+        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
+        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+
+        if h1 | h2 | h3 | h4 == 0xff {
+            return Err(());
+        }
+
+        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
+        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
+        j += 1;
+    }
+
+    Ok(buf)
+}
+
+/// Crockford's base32 alphabet, used by the ULID format.
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The base57 alphabet of the shortuuid format.
+pub(crate) const SHORTUUID_ALPHABET: &[u8; 57] =
+    b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const HEX_TABLE: &[u8; 256] = &{
+    let mut buf = [0; 256];
+    let mut i: u8 = 0;
+
+    loop {
+        buf[i as usize] = match i {
+            b'0'..=b'9' => i - b'0',
+            b'a'..=b'f' => i - b'a' + 10,
+            b'A'..=b'F' => i - b'A' + 10,
+            _ => 0xff,
+        };
+
+        if i == 255 {
+            break buf;
+        }
+
+        i += 1
+    }
+};
+
+const SHL4_TABLE: &[u8; 256] = &{
+    let mut buf = [0; 256];
+    let mut i: u8 = 0;
+
+    loop {
+        buf[i as usize] = i.wrapping_shl(4);
+
+        if i == 255 {
+            break buf;
         }
 
         i += 1;
@@ -274,7 +1022,10 @@ const SHL4_TABLE: &[u8; 256] = &{
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{std::string::ToString, tests::new};
+    use crate::{
+        std::string::ToString,
+        tests::{new, new2},
+    };
 
     #[test]
     fn test_parse_uuid_v4_valid() {
@@ -312,33 +1063,26 @@ mod tests {
         // Invalid
         assert_eq!(
             Uuid::parse_str(""),
-            Err(Error(ErrorKind::SimpleLength { len: 0 }))
+            Err(Error(ErrorKind::InputLength { len: 0 }))
+        );
+        assert_eq!(
+            "invalid length: expected length 32, 36, 38, 45, or 47, found 0",
+            Uuid::parse_str("").unwrap_err().to_string()
         );
 
         assert_eq!(
             Uuid::parse_str("!"),
-            Err(Error(ErrorKind::Char {
-                character: '!',
-                index: 1,
-            }))
+            Err(Error(ErrorKind::InputLength { len: 1 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45"),
-            Err(Error(ErrorKind::GroupLength {
-                group: 4,
-                len: 13,
-                index: 25,
-            }))
+            Err(Error(ErrorKind::InputLength { len: 37 }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-BBF-329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupLength {
-                group: 3,
-                len: 3,
-                index: 20,
-            }))
+            Err(Error(ErrorKind::InputLength { len: 35 }))
         );
 
         assert_eq!(
@@ -366,7 +1110,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorKind::InputLength { len: 18 }))
         );
 
         assert_eq!(
@@ -387,7 +1131,7 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{F9168C5E-CEB2-4faa9B6BFF329BF39FA1E41}"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorKind::InputLength { len: 39 }))
         );
 
         assert_eq!(
@@ -412,20 +1156,17 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::SimpleLength { len: 31 }))
+            Err(Error(ErrorKind::InputLength { len: 31 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c88"),
-            Err(Error(ErrorKind::SimpleLength { len: 33 }))
+            Err(Error(ErrorKind::InputLength { len: 33 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0cg8"),
-            Err(Error(ErrorKind::Char {
-                character: 'g',
-                index: 32,
-            }))
+            Err(Error(ErrorKind::InputLength { len: 33 }))
         );
 
         assert_eq!(
@@ -443,12 +1184,12 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{00000000000000000000000000000000}"),
-            Err(Error(ErrorKind::GroupCount { count: 1 }))
+            Err(Error(ErrorKind::InputLength { len: 34 }))
         );
 
         assert_eq!(
             Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::SimpleLength { len: 31 }))
+            Err(Error(ErrorKind::InputLength { len: 31 }))
         );
 
         assert_eq!(
@@ -514,6 +1255,676 @@ mod tests {
         assert_eq!(uuid_orig, uuid_out);
     }
 
+    #[test]
+    fn test_parse_urn_prefix_case_insensitive() {
+        let expected = Uuid::parse_str("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        for prefix in &["Urn:Uuid:", "URN:UUID:", "uRn:uUiD:", "urn:UUID:"] {
+            let s = format!("{}67e55044-10b1-426f-9247-bb680e5fe0c8", prefix);
+
+            assert_eq!(expected, Uuid::parse_str(&s).unwrap(), "{}", s);
+            assert_eq!(
+                expected,
+                Uuid::try_parse_ascii(s.as_bytes()).unwrap(),
+                "{}",
+                s
+            );
+        }
+
+        // The hex body is still validated, with indexes past the prefix
+        assert_eq!(
+            Uuid::parse_str("Urn:Uuid:67e55044-10b1-426f-9247-bb680e5fe0cG"),
+            Err(Error(ErrorKind::Char {
+                character: 'G',
+                index: 45,
+            }))
+        );
+
+        // Only the prefix letters are folded, not its separators
+        assert!(Uuid::parse_str("Urn;Uuid:67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+        assert!(Uuid::parse_str("Urm:Uuid:67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+    }
+
+    #[test]
+    fn test_from_decimal_str() {
+        assert_eq!(
+            Uuid::from_decimal_str("331094848530093083170738142201201533412"),
+            Ok(new())
+        );
+        assert_eq!(Uuid::from_decimal_str("0"), Ok(Uuid::nil()));
+        assert_eq!(Uuid::from_decimal_str("000001"), Ok(Uuid::from_u128(1)));
+        assert_eq!(
+            Uuid::from_decimal_str("340282366920938463463374607431768211455"),
+            Ok(Uuid::from_u128(u128::MAX))
+        );
+
+        assert_eq!(
+            Uuid::from_decimal_str(""),
+            Err(Error(ErrorKind::DecimalEmpty))
+        );
+        assert_eq!(
+            Uuid::from_decimal_str("340282366920938463463374607431768211456"),
+            Err(Error(ErrorKind::DecimalOverflow))
+        );
+        assert_eq!(
+            Uuid::from_decimal_str("1000000000000000000000000000000000000000"),
+            Err(Error(ErrorKind::DecimalOverflow))
+        );
+        assert_eq!(
+            Uuid::from_decimal_str("-1"),
+            Err(Error(ErrorKind::Char {
+                character: '-',
+                index: 1,
+            }))
+        );
+        assert_eq!(
+            Uuid::from_decimal_str("12a"),
+            Err(Error(ErrorKind::Char {
+                character: 'a',
+                index: 3,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_from_ulid_str() {
+        let expected = Uuid::from_u128(0x01563e3ab5d3d6764c61efb99302bd5b);
+
+        assert_eq!(
+            Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAV"),
+            Ok(expected)
+        );
+        assert_eq!(
+            Uuid::from_ulid_str("01arz3ndektsv4rrffq69g5fav"),
+            Ok(expected)
+        );
+        assert_eq!(
+            Uuid::from_ulid_str("00000000000000000000000000"),
+            Ok(Uuid::nil())
+        );
+        assert_eq!(
+            Uuid::from_ulid_str("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Ok(Uuid::from_u128(u128::MAX))
+        );
+
+        assert_eq!(
+            Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FA"),
+            Err(Error(ErrorKind::UlidLength { len: 25 }))
+        );
+        assert_eq!(
+            Uuid::from_ulid_str("80000000000000000000000000"),
+            Err(Error(ErrorKind::UlidOverflow))
+        );
+        assert_eq!(
+            Uuid::from_ulid_str("01ARZ3NDEKTSV4RRFFQ69G5FAU"),
+            Err(Error(ErrorKind::Char {
+                character: 'U',
+                index: 26,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_repr() {
+        assert_eq!(
+            Uuid::parse("F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4", Repr::Hex),
+            Ok(new())
+        );
+        assert_eq!(
+            Uuid::parse("331094848530093083170738142201201533412", Repr::Decimal),
+            Ok(new())
+        );
+        assert_eq!(
+            Uuid::parse("nL9J5yU6xWeCHWdWD7XGTK", Repr::ShortUuid),
+            Ok(new())
+        );
+
+        assert_eq!(
+            Uuid::parse("F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4", Repr::Decimal),
+            Err(Error(ErrorKind::Char {
+                character: 'F',
+                index: 1,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse("331094848530093083170738142201201533412", Repr::ShortUuid),
+            Err(Error(ErrorKind::ShortUuidLength { len: 39 }))
+        );
+        assert_eq!(
+            Uuid::parse("nL9J5yU6xWeCHWdWD7XGTK", Repr::Hex),
+            Uuid::parse_str("nL9J5yU6xWeCHWdWD7XGTK")
+        );
+    }
+
+    #[test]
+    fn test_from_shortuuid() {
+        assert_eq!(Uuid::from_shortuuid("nL9J5yU6xWeCHWdWD7XGTK"), Ok(new()));
+        assert_eq!(
+            Uuid::from_shortuuid("2222222222222222222222"),
+            Ok(Uuid::nil())
+        );
+        assert_eq!(
+            Uuid::from_shortuuid("oZEq7ovRbLq6UnGMPwc8B5"),
+            Ok(Uuid::from_u128(u128::MAX))
+        );
+
+        // Ambiguous characters aren't in the alphabet
+        assert_eq!(
+            Uuid::from_shortuuid("nL9J5yU6xWeCHWdWD7XGT0"),
+            Err(Error(ErrorKind::Char {
+                character: '0',
+                index: 22,
+            }))
+        );
+        assert_eq!(
+            Uuid::from_shortuuid("nL9J5yU6xWeCHWdWD7XGTK2"),
+            Err(Error(ErrorKind::ShortUuidLength { len: 23 }))
+        );
+        assert_eq!(
+            Uuid::from_shortuuid(""),
+            Err(Error(ErrorKind::ShortUuidLength { len: 0 }))
+        );
+        assert_eq!(
+            Uuid::from_shortuuid("oZEq7ovRbLq6UnGMPwc8B6"),
+            Err(Error(ErrorKind::ShortUuidOverflow))
+        );
+    }
+
+    #[test]
+    fn test_parse_str_with_format() {
+        let expected = new();
+
+        for (input, format) in [
+            ("f9168c5eceb24faab6bf329bf39fa1e4", Format::Simple),
+            ("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4", Format::Hyphenated),
+            ("URN:UUID:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4", Format::Urn),
+            ("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}", Format::Braced),
+            (
+                "(f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)",
+                Format::Parenthesized,
+            ),
+        ] {
+            assert_eq!(
+                Ok((expected, format)),
+                Uuid::parse_str_with_format(input),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_str("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)").map(|_| ()),
+            Uuid::parse_str_with_format("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_parse_braced_urn() {
+        let expected = new();
+
+        for input in [
+            "{urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+            "{URN:UUID:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4}",
+        ] {
+            assert_eq!(Ok(expected), Uuid::parse_str(input), "{}", input);
+            assert_eq!(
+                Ok(expected),
+                Uuid::try_parse_ascii(input.as_bytes()),
+                "{}",
+                input
+            );
+            assert_eq!(
+                Ok((expected, Format::Urn)),
+                Uuid::parse_str_with_format(input)
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_str("{urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1eX}"),
+            Err(Error(ErrorKind::Char {
+                character: 'X',
+                index: 46,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_str("{urn:uuid:f9168c5e-ceb2-4faa-b6bf0-329bf39fa1e}"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 3,
+                len: 5,
+                index: 30,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_str("{f9168c5e-ceb2-4faa-b6bf0-329bf39fa1e}"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 3,
+                len: 5,
+                index: 21,
+            }))
+        );
+
+        // The wrappers only nest one way
+        assert!(Uuid::parse_str("urn:uuid:{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}").is_err());
+        assert!(Uuid::parse_str("{urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4)").is_err());
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        let uuid = new();
+
+        for pattern in [
+            "*-*-*-*-*",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            "F9168C5E-*-*-*-*",
+            "*-*-*-*-329bf39fa1e4",
+            "????????-????-4???-????-????????????",
+            "*-ceb2-*-b6?f-*",
+        ] {
+            assert_eq!(Ok(true), uuid.matches_pattern(pattern), "{}", pattern);
+        }
+
+        for pattern in [
+            "00000000-*-*-*-*",
+            "*-*-*-*-329bf39fa1e5",
+            "????????-????-5???-????-????????????",
+        ] {
+            assert_eq!(Ok(false), uuid.matches_pattern(pattern), "{}", pattern);
+        }
+
+        assert_eq!(
+            uuid.matches_pattern("f9168c5e-*"),
+            Err(Error(ErrorKind::GroupCount { count: 2 }))
+        );
+        assert_eq!(
+            uuid.matches_pattern("*-*-*-*-*-*"),
+            Err(Error(ErrorKind::GroupCount { count: 6 }))
+        );
+        assert_eq!(
+            uuid.matches_pattern("*-ceb-*-*-*"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 1,
+                len: 3,
+                index: 3,
+            }))
+        );
+        assert_eq!(
+            uuid.matches_pattern("*-*-4fa*-*-*"),
+            Err(Error(ErrorKind::Char {
+                character: '*',
+                index: 8,
+            }))
+        );
+        assert_eq!(
+            uuid.matches_pattern("0000000g-*-*-*-*"),
+            Err(Error(ErrorKind::Char {
+                character: 'g',
+                index: 8,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        let input = "F9168C5E-ceb2-4faa-b6bf-329bf39fa1e4";
+        let groups = Uuid::parse_groups(input).unwrap();
+
+        assert_eq!(
+            ["F9168C5E", "ceb2", "4faa", "b6bf", "329bf39fa1e4"],
+            [
+                &input[groups[0].range()],
+                &input[groups[1].range()],
+                &input[groups[2].range()],
+                &input[groups[3].range()],
+                &input[groups[4].range()],
+            ]
+        );
+        assert_eq!((24, 36), (groups[4].start(), groups[4].end()));
+
+        // Partial input stops at the first incomplete group
+        assert_eq!(
+            Uuid::parse_groups("f9168c5e-ce"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 1,
+                len: 2,
+                index: 10,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_groups("f9168c5e-ceb2"),
+            Err(Error(ErrorKind::GroupCount { count: 2 }))
+        );
+        assert_eq!(
+            Uuid::parse_groups(""),
+            Err(Error(ErrorKind::GroupLength {
+                group: 0,
+                len: 0,
+                index: 1,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_groups("f9168c5e-ceb2-4fga-b6bf-329bf39fa1e4"),
+            Err(Error(ErrorKind::Char {
+                character: 'g',
+                index: 17,
+            }))
+        );
+        assert_eq!(
+            Uuid::parse_groups("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4-"),
+            Err(Error(ErrorKind::GroupCount { count: 6 }))
+        );
+        assert_eq!(
+            Uuid::parse_groups("f9168c5eceb24faab6bf329bf39fa1e4"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 0,
+                len: 32,
+                index: 1,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_try_parse_ascii_const() {
+        const VALID: Result<Uuid, Error> =
+            Uuid::try_parse_ascii(b"f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4");
+        const INVALID: Result<Uuid, Error> =
+            Uuid::try_parse_ascii(b"f9168c5e-ceb2-4faa-b6bf-329bf39fa1eG");
+
+        assert_eq!(Ok(new()), VALID);
+        assert!(INVALID.is_err());
+    }
+
+    #[test]
+    fn test_parse_parenthesized() {
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(
+            Ok(expected),
+            Uuid::parse_str("(67e55044-10b1-426f-9247-bb680e5fe0c8)")
+        );
+        assert_eq!(
+            Ok(expected),
+            Uuid::try_parse("(67E55044-10B1-426F-9247-BB680E5FE0C8)")
+        );
+
+        // Indexes of invalid characters account for the opening parenthesis
+        assert_eq!(
+            Uuid::parse_str("(67e55044-10b1-426f-9247-bb680e5fe0cG)"),
+            Err(Error(ErrorKind::Char {
+                character: 'G',
+                index: 37,
+            }))
+        );
+
+        // The parentheses must be balanced, and can't wrap a simple UUID
+        assert!(Uuid::parse_str("(67e55044-10b1-426f-9247-bb680e5fe0c8}").is_err());
+        assert!(Uuid::parse_str("(67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+        assert!(Uuid::parse_str("(67e5504410b1426f9247bb680e5fe0c8)").is_err());
+    }
+
+    #[test]
+    fn test_parse_invisible_characters() {
+        // A leading byte order mark
+        assert_eq!(
+            Uuid::parse_str("\u{FEFF}67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Err(Error(ErrorKind::Char {
+                character: '\u{FEFF}',
+                index: 1,
+            }))
+        );
+
+        // A trailing zero-width space, even outside of braces
+        assert_eq!(
+            Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c8}\u{200B}"),
+            Err(Error(ErrorKind::Char {
+                character: '\u{200B}',
+                index: 39,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c8\u{2060}")
+                .unwrap_err()
+                .to_string(),
+            "invalid character: expected an optional prefix of `urn:uuid:` followed by [0-9a-fA-F-], found invisible character `U+2060` at 33"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_error_as_std_error() {
+        use crate::std::{boxed::Box, error};
+
+        let err: Box<dyn error::Error> =
+            Box::new(Uuid::parse_str("67e55044-10b-426f-9247-bb680e5fe0c8a").unwrap_err());
+
+        assert!(err.source().is_none());
+        assert_eq!(
+            err.to_string(),
+            "invalid group length in group 1 at 10: expected 4, found 3"
+        );
+    }
+
+    #[test]
+    fn test_is_supported_len() {
+        for len in 0..64 {
+            assert_eq!(
+                is_supported_len(len),
+                [
+                    Uuid::nil().simple().to_string(),
+                    Uuid::nil().hyphenated().to_string(),
+                    Uuid::nil().braced().to_string(),
+                    Uuid::nil().urn().to_string(),
+                    format!("({})", Uuid::nil().hyphenated()),
+                    format!("{{{}}}", Uuid::nil().urn()),
+                ]
+                .iter()
+                .any(|s| s.len() == len),
+                "{}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_str_light() {
+        assert_eq!(
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").ok(),
+            Uuid::parse_str_light("67e55044-10b1-426f-9247-bb680e5fe0c8").ok()
+        );
+
+        for (input, expected) in &[
+            ("", ParseErrorKind::Length),
+            ("!", ParseErrorKind::Length),
+            ("67e5504410b1426f9247bb680e5fe0c", ParseErrorKind::Length),
+            (
+                "67e55044-10b1-426f-9247-bb680e5fe0c8a",
+                ParseErrorKind::Length,
+            ),
+            (
+                "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c",
+                ParseErrorKind::Length,
+            ),
+            ("67e55044-10b1-426f-9247-bb680e5fe0cG", ParseErrorKind::Char),
+            ("67e5504410b1426f9247bb680e5fe0cG", ParseErrorKind::Char),
+            (
+                "{67e55044-10b1-426f-9247-bb680e5fe0c8)",
+                ParseErrorKind::Char,
+            ),
+            (
+                "{urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0cG}",
+                ParseErrorKind::Char,
+            ),
+            (
+                "67e550-4105b1426f9247bb680e5fe0c",
+                ParseErrorKind::GroupCount,
+            ),
+            (
+                "F9168C5E-CEB2-4faa-B6BF1-02BF39FA1E4",
+                ParseErrorKind::GroupLength,
+            ),
+        ] {
+            let err = Uuid::parse_str_light(input).unwrap_err();
+
+            assert_eq!(*expected, err, "{}", input);
+            assert_eq!(
+                Uuid::parse_str(input).unwrap_err().0.parse_error_kind(),
+                err,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_str_unquoted() {
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        for input in &[
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            "'67e55044-10b1-426f-9247-bb680e5fe0c8'",
+            "'{67e55044-10b1-426f-9247-bb680e5fe0c8}'",
+            "\"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8\"",
+            "'67e5504410b1426f9247bb680e5fe0c8'",
+        ] {
+            assert_eq!(Ok(expected), Uuid::parse_str_unquoted(input), "{}", input);
+        }
+
+        // Only a single layer of matching quotes is removed
+        assert!(Uuid::parse_str_unquoted("\"'67e55044-10b1-426f-9247-bb680e5fe0c8'\"").is_err());
+        assert!(Uuid::parse_str_unquoted("\"67e55044-10b1-426f-9247-bb680e5fe0c8'").is_err());
+        assert!(Uuid::parse_str_unquoted("'67e55044-10b1-426f-9247-bb680e5fe0c8").is_err());
+        assert!(Uuid::parse_str_unquoted("`67e55044-10b1-426f-9247-bb680e5fe0c8`").is_err());
+
+        // Errors are reported against the unquoted input
+        assert_eq!(
+            Uuid::parse_str_unquoted("'67e55044-10b1-426f-9247-bb680e5fe0cG'"),
+            Err(Error(ErrorKind::Char {
+                character: 'G',
+                index: 36,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_str_unquoted("\"\""),
+            Err(Error(ErrorKind::InputLength { len: 0 }))
+        );
+        assert!(Uuid::parse_str_unquoted("\"").is_err());
+    }
+
+    #[test]
+    fn test_eq_pg_text() {
+        let uuid = new();
+
+        assert!(uuid.eq_pg_text("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"));
+        assert!(uuid.eq_pg_text("F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4"));
+        assert!(uuid.eq_pg_text("'f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4'"));
+        assert!(uuid.eq_pg_text("'F9168C5E-ceb2-4FAA-b6bf-329BF39FA1E4'"));
+
+        assert!(!uuid.eq_pg_text("\"f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4\""));
+        assert!(!uuid.eq_pg_text("''f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4''"));
+        assert!(!uuid.eq_pg_text("'f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"));
+        assert!(!uuid.eq_pg_text("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e5"));
+        assert!(!uuid.eq_pg_text("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}"));
+        assert!(!uuid.eq_pg_text(""));
+        assert!(!new2().eq_pg_text("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"));
+    }
+
+    #[test]
+    fn test_parse_with_checksum() {
+        let uuid = new();
+        // The bytes of `new` sum to 0x09cb
+        let checksum = "CB";
+
+        for input in &[
+            format!("{}:{}", uuid.hyphenated(), checksum),
+            format!("{}:{}", uuid.simple(), checksum),
+            format!("{}:{}", uuid.urn(), checksum),
+            format!("{}:{}", uuid.braced(), checksum),
+            format!("{}:cb", uuid.hyphenated()),
+        ] {
+            assert_eq!(Ok(uuid), Uuid::parse_with_checksum(input), "{}", input);
+        }
+
+        assert_eq!(0, Uuid::nil().checksum());
+        assert_eq!(
+            Ok(Uuid::nil()),
+            Uuid::parse_with_checksum("00000000-0000-0000-0000-000000000000:00")
+        );
+
+        assert_eq!(
+            Uuid::parse_with_checksum("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:CA"),
+            Err(Error(ErrorKind::ChecksumMismatch {
+                expected: 0xcb,
+                found: 0xca,
+            }))
+        );
+
+        for input in &[
+            "",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:C",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:CBB",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4CB",
+        ] {
+            assert_eq!(
+                Uuid::parse_with_checksum(input),
+                Err(Error(ErrorKind::MissingChecksum)),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_with_checksum("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:CG"),
+            Err(Error(ErrorKind::Char {
+                character: 'G',
+                index: 39,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_with_checksum("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4:é"),
+            Err(Error(ErrorKind::Char {
+                character: 'é',
+                index: 38,
+            }))
+        );
+
+        // Errors in the UUID itself are reported the same way as `parse_str`
+        assert_eq!(
+            Uuid::parse_with_checksum("f9168c5e-ceb2-4faa-b6bf-329bf39fa1eG:CB"),
+            Err(Error(ErrorKind::Char {
+                character: 'G',
+                index: 36,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8\r\n\
+            \n\
+            \r\n\
+            {67e55044-10b1-426f-9247-bb680e5fe0c8}\n\
+            not-a-uuid\n\
+            67e5504410b1426f9247bb680e5fe0c8";
+
+        let parsed = Uuid::parse_lines(input).collect::<crate::std::vec::Vec<_>>();
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(4, parsed.len());
+        assert_eq!(Ok(expected), parsed[0]);
+        assert_eq!(Ok(expected), parsed[1]);
+        assert_eq!(Err(Uuid::parse_str("not-a-uuid").unwrap_err()), parsed[2]);
+        assert_eq!(Ok(expected), parsed[3]);
+
+        assert_eq!(0, Uuid::parse_lines("").count());
+        assert_eq!(0, Uuid::parse_lines("\n\r\n\n").count());
+    }
+
     #[test]
     fn test_try_parse_ascii_non_utf8() {
         assert!(Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5\0e0c8").is_err());
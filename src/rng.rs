@@ -1,4 +1,9 @@
-#[cfg(any(feature = "v4", feature = "v7"))]
+#[cfg(any(
+    feature = "v1",
+    feature = "v4",
+    all(feature = "v6", feature = "std"),
+    feature = "v7"
+))]
 pub(crate) fn bytes() -> [u8; 16] {
     #[cfg(not(feature = "fast-rng"))]
     {
@@ -18,6 +23,15 @@ pub(crate) fn bytes() -> [u8; 16] {
     }
 }
 
+#[cfg(all(feature = "v4", not(feature = "fast-rng")))]
+pub(crate) fn try_bytes() -> Result<[u8; 16], getrandom::Error> {
+    let mut bytes = [0u8; 16];
+
+    getrandom::getrandom(&mut bytes)?;
+
+    Ok(bytes)
+}
+
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub(crate) fn u16() -> u16 {
     #[cfg(not(feature = "fast-rng"))]
@@ -0,0 +1,126 @@
+//! Utilities for making generated UUIDs reproducible in tests.
+//!
+//! Note that usage of this module requires the `test-util` feature of this
+//! crate to be enabled. It isn't intended to be enabled outside of
+//! dev-dependencies.
+
+// NOTE: `const` initializers for `thread_local!` need a newer MSRV
+#![allow(clippy::missing_const_for_thread_local)]
+
+use crate::std::cell::Cell;
+
+thread_local! {
+    static SEED: Cell<Option<u64>> = Cell::new(None);
+}
+
+/// Runs `f` with [`Uuid::new_v4`] generating UUIDs from `seed` instead of
+/// the operating system's RNG.
+///
+/// The seed only applies to the current thread, and only until `f` returns
+/// or panics. Calls with the same seed produce the same sequence of UUIDs,
+/// which is useful for snapshot tests. Nested calls use the innermost seed,
+/// and the outer sequence carries on once they return.
+///
+/// The generator isn't suitable for anything but tests: its output is
+/// predictable from the seed.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{test_util::with_deterministic_v4, Uuid};
+/// let a = with_deterministic_v4(42, || (Uuid::new_v4(), Uuid::new_v4()));
+/// let b = with_deterministic_v4(42, || (Uuid::new_v4(), Uuid::new_v4()));
+///
+/// assert_eq!(a, b);
+/// assert_ne!(a.0, a.1);
+/// ```
+///
+/// [`Uuid::new_v4`]: ../struct.Uuid.html#method.new_v4
+pub fn with_deterministic_v4<R>(seed: u64, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<u64>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SEED.with(|state| state.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SEED.with(|state| state.replace(Some(seed))));
+
+    f()
+}
+
+/// Get the next random bytes for the current thread, if it has a seed.
+pub(crate) fn bytes() -> Option<[u8; 16]> {
+    SEED.with(|state| {
+        let mut seed = state.get()?;
+
+        let high = splitmix64(&mut seed);
+        let low = splitmix64(&mut seed);
+
+        state.set(Some(seed));
+
+        Some((((high as u128) << 64) | low as u128).to_be_bytes())
+    })
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Uuid, Variant, Version};
+
+    #[test]
+    fn test_with_deterministic_v4() {
+        let generate = || [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+
+        let a = with_deterministic_v4(1, generate);
+        let b = with_deterministic_v4(1, generate);
+        let c = with_deterministic_v4(2, generate);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a[0], a[1]);
+
+        for uuid in a.iter() {
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+        }
+
+        // Outside of the closure generation goes back to the OS RNG
+        assert_eq!(None, bytes());
+    }
+
+    #[test]
+    fn test_with_deterministic_v4_nested() {
+        let expected = with_deterministic_v4(1, || [Uuid::new_v4(), Uuid::new_v4()]);
+
+        let (outer, inner) = with_deterministic_v4(1, || {
+            let first = Uuid::new_v4();
+            let inner = with_deterministic_v4(2, Uuid::new_v4);
+
+            ([first, Uuid::new_v4()], inner)
+        });
+
+        assert_eq!(expected, outer);
+        assert_eq!(with_deterministic_v4(2, Uuid::new_v4), inner);
+    }
+
+    #[test]
+    fn test_with_deterministic_v4_panic() {
+        let result = crate::std::panic::catch_unwind(|| {
+            with_deterministic_v4(1, || panic!("test"));
+        });
+
+        assert!(result.is_err());
+        assert_eq!(None, bytes());
+    }
+}
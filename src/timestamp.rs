@@ -125,9 +125,7 @@ impl Timestamp {
 
     #[cfg(any(feature = "v1", feature = "v6"))]
     const fn unix_to_rfc4122_ticks(seconds: u64, nanos: u32) -> u64 {
-        let ticks = UUID_TICKS_BETWEEN_EPOCHS + seconds * 10_000_000 + nanos as u64 / 100;
-
-        ticks
+        UUID_TICKS_BETWEEN_EPOCHS + seconds * 10_000_000 + nanos as u64 / 100
     }
 
     const fn rfc4122_to_unix(ticks: u64) -> (u64, u32) {
@@ -305,7 +303,7 @@ pub trait ClockSequence {
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output;
 }
 
-impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
+impl<T: ClockSequence + ?Sized> ClockSequence for &T {
     type Output = T::Output;
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
         (**self).generate_sequence(seconds, subsec_nanos)
@@ -372,6 +370,10 @@ pub mod context {
         /// The starting value should be a random number, so that UUIDs from
         /// different systems with the same timestamps are less likely to collide.
         /// When the `rng` feature is enabled, prefer the [`Context::new_random`] method.
+        ///
+        /// A fixed value is useful in tests, where it makes the clock sequences
+        /// of generated UUIDs deterministic. It's also the only option in
+        /// `const` contexts, such as initializing a `static`.
         pub const fn new(count: u16) -> Self {
             Self {
                 count: Atomic::<u16>::new(count),
@@ -379,6 +381,18 @@ pub mod context {
         }
 
         /// Construct a new context that's initialized with a random value.
+        ///
+        /// Seeding the clock sequence from entropy means a process that restarts,
+        /// or runs alongside others with the same node ID, is unlikely to repeat
+        /// clock sequences that were already used for the same timestamps. The
+        /// trade-off is that the generated UUIDs aren't reproducible, so use
+        /// [`Context::new`] with a fixed value where that matters, like in tests.
+        ///
+        /// [`Uuid::now_v1`] and [`Uuid::now_v6`] use a shared context that's
+        /// seeded this way the first time it's used.
+        ///
+        /// [`Uuid::now_v1`]: ../struct.Uuid.html#method.now_v1
+        /// [`Uuid::now_v6`]: ../struct.Uuid.html#method.now_v6
         #[cfg(feature = "rng")]
         pub fn new_random() -> Self {
             Self {
@@ -3,7 +3,7 @@
 //! This module is soft-deprecated. Instead of using the `Context` type re-exported here,
 //! use the one from the crate root.
 
-use crate::{Builder, Uuid};
+use crate::{std::time::Duration, Builder, Uuid};
 
 #[deprecated(note = "use types from the crate root instead")]
 pub use crate::{timestamp::context::Context, Timestamp};
@@ -93,6 +93,118 @@ impl Uuid {
 
         Builder::from_rfc4122_timestamp(ticks, counter, node_id).into_uuid()
     }
+
+    /// Create a new version 1 UUID using the given timestamp and a node ID
+    /// stored in the low 48 bits of a `u64`.
+    ///
+    /// This is the same as [`Uuid::new_v1`], but accepts node IDs, such as
+    /// MAC addresses, that are represented as integers. The top 16 bits of
+    /// `node_id` are ignored.
+    ///
+    /// Note that usage of this method requires the `v1` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Timestamp, Context};
+    /// # use uuid::Uuid;
+    /// # fn random_seed() -> u16 { 42 }
+    /// let context = Context::new(random_seed());
+    /// let ts = Timestamp::from_unix(&context, 1497624119, 1234);
+    ///
+    /// let uuid = Uuid::new_v1_u64_node(ts, 0x0102_0304_0506);
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated().to_string(),
+    ///     "f3b4958c-52a1-11e7-802a-010203040506"
+    /// );
+    /// ```
+    pub fn new_v1_u64_node(ts: Timestamp, node_id: u64) -> Self {
+        let node = node_id.to_be_bytes();
+
+        Self::new_v1(ts, &[node[2], node[3], node[4], node[5], node[6], node[7]])
+    }
+
+    /// Create a new version 1 UUID from a node ID, the time since the Unix
+    /// epoch, and a counter.
+    ///
+    /// Version 1 timestamps count 100 nanosecond intervals since the start
+    /// of the Gregorian calendar in 1582 rather than the Unix epoch. This
+    /// method does that conversion, so `since_epoch` can come straight from
+    /// something like `SystemTime::duration_since(UNIX_EPOCH)`. The low 14
+    /// bits of `counter` are used as the clock sequence.
+    ///
+    /// Note that usage of this method requires the `v1` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::new_v1_from_unix([1, 2, 3, 4, 5, 6], Duration::new(1497624119, 1234), 42);
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated().to_string(),
+    ///     "f3b4958c-52a1-11e7-802a-010203040506"
+    /// );
+    /// ```
+    pub fn new_v1_from_unix(node_id: [u8; 6], since_epoch: Duration, counter: u16) -> Self {
+        let ts = Timestamp {
+            seconds: since_epoch.as_secs(),
+            nanos: since_epoch.subsec_nanos(),
+            counter,
+        };
+
+        Self::new_v1(ts, &node_id)
+    }
+
+    /// Create a new version 1 UUID using the given timestamp and a random
+    /// node ID.
+    ///
+    /// The multicast bit of the node ID is set, as RFC4122 recommends for
+    /// node IDs that aren't a MAC address. This is the same as the
+    /// `uuid_generate_v1mc` function of Postgres, and avoids leaking the
+    /// address of the generating machine.
+    ///
+    /// Note that usage of this method requires the `v1` and `rng` features of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Timestamp, Context};
+    /// # use uuid::{Uuid, Version};
+    /// # fn random_seed() -> u16 { 42 }
+    /// let context = Context::new(random_seed());
+    /// let ts = Timestamp::from_unix(&context, 1497624119, 1234);
+    ///
+    /// let uuid = Uuid::new_v1_random_node(ts);
+    ///
+    /// assert_eq!(Some(Version::Mac), uuid.get_version());
+    /// assert_eq!(Some(true), uuid.is_multicast_node());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Node IDs that do not identify the host in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.5)
+    #[cfg(feature = "rng")]
+    pub fn new_v1_random_node(ts: Timestamp) -> Self {
+        let bytes = crate::rng::bytes();
+
+        Self::new_v1(
+            ts,
+            &[
+                bytes[0] | 0x01,
+                bytes[1],
+                bytes[2],
+                bytes[3],
+                bytes[4],
+                bytes[5],
+            ],
+        )
+    }
 }
 
 #[cfg(test)]
@@ -172,4 +284,74 @@ mod tests {
         assert_eq!(uuid3.get_timestamp().unwrap().to_rfc4122().1, 1);
         assert_eq!(uuid4.get_timestamp().unwrap().to_rfc4122().1, 2);
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_u64_node() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let context = Context::new(0);
+
+        let uuid = Uuid::new_v1_u64_node(
+            Timestamp::from_unix(&context, time, time_fraction),
+            0xffff_0102_0304_0506,
+        );
+
+        assert_eq!(uuid.get_version(), Some(Version::Mac));
+        assert_eq!(uuid.get_node_id(), Some([1, 2, 3, 4, 5, 6]));
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "20616934-4ba2-11e7-8000-010203040506"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_from_unix() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let uuid = Uuid::new_v1_from_unix(node, Duration::new(time, time_fraction), 0);
+
+        assert_eq!(uuid.get_version(), Some(Version::Mac));
+        assert_eq!(
+            uuid,
+            Uuid::new_v1(
+                Timestamp::from_unix(Context::new(0), time, time_fraction),
+                &node
+            )
+        );
+        assert_eq!(
+            uuid.get_timestamp().unwrap().to_unix(),
+            (time, time_fraction)
+        );
+
+        let uuid = Uuid::new_v1_from_unix(node, Duration::new(time, time_fraction), 0xffff);
+
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(&[0xbf, 0xff], &uuid.as_bytes()[8..10]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "rng")]
+    fn test_new_random_node() {
+        let time: u64 = 1_496_854_535;
+        let time_fraction: u32 = 812_946_000;
+        let context = Context::new(0);
+
+        let ts = Timestamp::from_unix(&context, time, time_fraction);
+        let uuid = Uuid::new_v1_random_node(ts);
+
+        assert_eq!(uuid.get_version(), Some(Version::Mac));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid.is_multicast_node(), Some(true));
+        assert_eq!(uuid.get_timestamp().unwrap().to_rfc4122(), ts.to_rfc4122());
+
+        assert_ne!(
+            uuid.get_node_id(),
+            Uuid::new_v1_random_node(ts).get_node_id()
+        );
+    }
 }
@@ -1,5 +1,33 @@
 use crate::Uuid;
 
+/// A hash function used to generate version 3 UUIDs.
+///
+/// Version 3 UUIDs are specified to use MD5, which is what [`Uuid::new_v3`]
+/// uses through [`Md5Hasher`]. Implementing this trait and passing it to
+/// [`Uuid::new_v3_with`] lets the digest be computed somewhere else instead,
+/// such as an audited cryptography module. The version and variant bits of
+/// the result are always set by this crate.
+///
+/// Note that usage of this trait requires the `v3` feature of this crate
+/// to be enabled.
+pub trait NamespaceHasher {
+    /// Hash the bytes of a namespace followed by a name.
+    ///
+    /// The result should be the first 16 bytes of the digest of `namespace`
+    /// concatenated with `name`.
+    fn hash(&self, namespace: &[u8], name: &[u8]) -> [u8; 16];
+}
+
+/// The default [`NamespaceHasher`] for version 3 UUIDs, based on MD5.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Md5Hasher;
+
+impl NamespaceHasher for Md5Hasher {
+    fn hash(&self, namespace: &[u8], name: &[u8]) -> [u8; 16] {
+        crate::md5::hash(namespace, name)
+    }
+}
+
 impl Uuid {
     /// Creates a UUID using a name from a namespace, based on the MD5
     /// hash.
@@ -34,7 +62,36 @@ impl Uuid {
     /// [`NAMESPACE_URL`]: #associatedconstant.NAMESPACE_URL
     /// [`NAMESPACE_X500`]: #associatedconstant.NAMESPACE_X500
     pub fn new_v3(namespace: &Uuid, name: &[u8]) -> Uuid {
-        crate::Builder::from_md5_bytes(crate::md5::hash(namespace.as_bytes(), name)).into_uuid()
+        Uuid::new_v3_with(&Md5Hasher, namespace, name)
+    }
+
+    /// Creates a UUID using a name from a namespace, based on a hash computed
+    /// by the supplied [`NamespaceHasher`].
+    ///
+    /// Using [`Md5Hasher`] gives the same result as [`Uuid::new_v3`]. Any other
+    /// hasher that computes an MD5 digest will produce UUIDs that are
+    /// compatible with it.
+    ///
+    /// Note that usage of this method requires the `v3` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Generating a DNS UUID for `rust-lang.org` with the default hasher:
+    ///
+    /// ```
+    /// # use uuid::{Md5Hasher, Uuid, Version};
+    /// let uuid = Uuid::new_v3_with(&Md5Hasher, &Uuid::NAMESPACE_DNS, b"rust-lang.org");
+    ///
+    /// assert_eq!(Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"rust-lang.org"), uuid);
+    /// assert_eq!(Some(Version::Md5), uuid.get_version());
+    /// ```
+    pub fn new_v3_with<H: NamespaceHasher + ?Sized>(
+        hasher: &H,
+        namespace: &Uuid,
+        name: &[u8],
+    ) -> Uuid {
+        crate::Builder::from_md5_bytes(hasher.hash(namespace.as_bytes(), name)).into_uuid()
     }
 }
 
@@ -148,4 +205,50 @@ mod tests {
             assert_eq!(uuid.hyphenated().to_string(), *expected);
         }
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_with_md5_hasher() {
+        for (ns, name, expected) in FIXTURE {
+            let uuid = Uuid::new_v3_with(&Md5Hasher, ns, name.as_bytes());
+            assert_eq!(uuid.hyphenated().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_with_custom_hasher() {
+        struct Delegate;
+
+        impl NamespaceHasher for Delegate {
+            fn hash(&self, namespace: &[u8], name: &[u8]) -> [u8; 16] {
+                Md5Hasher.hash(namespace, name)
+            }
+        }
+
+        struct Fill(u8);
+
+        impl NamespaceHasher for Fill {
+            fn hash(&self, _: &[u8], _: &[u8]) -> [u8; 16] {
+                [self.0; 16]
+            }
+        }
+
+        for (ns, name, _) in FIXTURE {
+            assert_eq!(
+                Uuid::new_v3(ns, name.as_bytes()),
+                Uuid::new_v3_with(&Delegate, ns, name.as_bytes())
+            );
+        }
+
+        let dyn_hasher: &dyn NamespaceHasher = &Fill(0xff);
+        let uuid = Uuid::new_v3_with(dyn_hasher, &Uuid::NAMESPACE_DNS, b"rust-lang.org");
+
+        assert_eq!(uuid.get_version(), Some(Version::Md5));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "ffffffff-ffff-3fff-bfff-ffffffffffff"
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Uuid;
+use crate::{Error, Uuid};
 
 impl Uuid {
     /// Creates a random UUID.
@@ -10,6 +10,10 @@ impl Uuid {
     /// [`uuid::Builder::from_random_bytes`][from_random_bytes] function
     /// instead.
     ///
+    /// When the `test-util` feature is enabled, UUIDs generated inside
+    /// [`test_util::with_deterministic_v4`] come from a seeded generator
+    /// instead.
+    ///
     /// Note that usage of this method requires the `v4` feature of this crate
     /// to be enabled.
     ///
@@ -24,15 +28,139 @@ impl Uuid {
     /// assert_eq!(Some(Version::Random), uuid.get_version());
     /// ```
     ///
+    /// # Panics
+    ///
+    /// Panics if the operating system's random number generator fails, for
+    /// example when it hasn't been seeded yet early in boot. Use
+    /// [`Uuid::try_new_v4`] to handle that case instead.
+    ///
     /// # References
     ///
     /// * [Version 4 UUIDs in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.4)
     ///
     /// [`getrandom`]: https://crates.io/crates/getrandom
     /// [from_random_bytes]: struct.Builder.html#method.from_random_bytes
+    /// [`test_util::with_deterministic_v4`]: test_util/fn.with_deterministic_v4.html
     pub fn new_v4() -> Uuid {
+        #[cfg(feature = "test-util")]
+        {
+            if let Some(bytes) = crate::test_util::bytes() {
+                return crate::Builder::from_random_bytes(bytes).into_uuid();
+            }
+        }
+
         crate::Builder::from_random_bytes(crate::rng::bytes()).into_uuid()
     }
+
+    /// Creates a random UUID, returning an error if random bytes can't be
+    /// retrieved.
+    ///
+    /// This is the same as [`Uuid::new_v4`], except an error from the
+    /// [`getrandom`] crate is returned instead of causing a panic. This is
+    /// useful for startup code that may run before the operating system's
+    /// random number generator is ready.
+    ///
+    /// When the `fast-rng` feature is enabled random bytes come from the
+    /// [`rand`] crate instead, which panics if it can't be seeded, so this
+    /// method never returns an error.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::try_new_v4()?;
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`getrandom`]: https://crates.io/crates/getrandom
+    /// [`rand`]: https://crates.io/crates/rand
+    pub fn try_new_v4() -> Result<Uuid, Error> {
+        #[cfg(feature = "test-util")]
+        {
+            if let Some(bytes) = crate::test_util::bytes() {
+                return Ok(crate::Builder::from_random_bytes(bytes).into_uuid());
+            }
+        }
+
+        #[cfg(not(feature = "fast-rng"))]
+        let bytes = crate::rng::try_bytes()
+            .map_err(|err| Error(crate::error::ErrorKind::Random { code: err.code() }))?;
+
+        #[cfg(feature = "fast-rng")]
+        let bytes = crate::rng::bytes();
+
+        Ok(crate::Builder::from_random_bytes(bytes).into_uuid())
+    }
+
+    /// Creates a random UUID between `low` and `high`, inclusive.
+    ///
+    /// The bounds are compared as integers, as in [`Uuid::as_u128`], and the
+    /// result is chosen uniformly from the range between them. The version and
+    /// variant bits aren't set, so the result is only a valid version 4 UUID
+    /// if every value in the range is. If `low` and `high` are equal then
+    /// `low` is returned.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high`.
+    ///
+    /// # Examples
+    ///
+    /// Generating a UUID with a fixed first group:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let low = Uuid::parse_str("a1a2a3a4-0000-0000-0000-000000000000")?;
+    /// let high = Uuid::parse_str("a1a2a3a4-ffff-ffff-ffff-ffffffffffff")?;
+    ///
+    /// let uuid = Uuid::random_in_range(low, high);
+    ///
+    /// assert!(low <= uuid && uuid <= high);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn random_in_range(low: Uuid, high: Uuid) -> Uuid {
+        let (low, high) = (low.as_u128(), high.as_u128());
+
+        assert!(
+            low <= high,
+            "the low bound must not be greater than the high bound"
+        );
+
+        let span = high - low;
+
+        // The whole range of `u128` can be sampled from directly
+        if span == u128::MAX {
+            return Uuid::from_bytes(crate::rng::bytes());
+        }
+
+        let range = span + 1;
+
+        // Values below the threshold are rejected so that the remainder
+        // isn't biased towards the low end of the range
+        let threshold = range.wrapping_neg() % range;
+
+        loop {
+            let value = Uuid::from_bytes(crate::rng::bytes()).as_u128();
+
+            if value >= threshold {
+                return Uuid::from_u128(low + value % range);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -52,6 +180,54 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_try_new() {
+        let uuid = Uuid::try_new_v4().unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_ne!(uuid, Uuid::try_new_v4().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_random_in_range() {
+        let low = Uuid::from_u128(0xa1a2a3a4 << 96);
+        let high = Uuid::from_u128(low.as_u128() | (u128::MAX >> 32));
+
+        for _ in 0..100 {
+            let uuid = Uuid::random_in_range(low, high);
+
+            assert!(low <= uuid && uuid <= high);
+            assert_eq!(0xa1a2a3a4, uuid.as_fields().0);
+        }
+
+        // A range of two values produces both of them
+        let low = Uuid::from_u128(41);
+        let high = Uuid::from_u128(42);
+
+        let mut seen = [false; 2];
+        for _ in 0..100 {
+            seen[(Uuid::random_in_range(low, high).as_u128() - 41) as usize] = true;
+        }
+        assert_eq!([true, true], seen);
+
+        let max = Uuid::from_u128(u128::MAX);
+
+        assert_eq!(low, Uuid::random_in_range(low, low));
+        assert_eq!(max, Uuid::random_in_range(max, max));
+
+        // The full range is allowed
+        Uuid::random_in_range(Uuid::nil(), max);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_in_range_inverted() {
+        Uuid::random_in_range(Uuid::from_u128(2), Uuid::from_u128(1));
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_get_version() {
@@ -1,4 +1,102 @@
-use crate::Uuid;
+use crate::{std::fmt, Uuid};
+
+/// An incremental hasher for version 5 UUIDs.
+///
+/// This produces the same UUID as [`Uuid::new_v5`], but the name can be
+/// fed in chunks with [`update`](#method.update) instead of being collected
+/// into a single slice first. That's useful for names like the contents of
+/// a file.
+///
+/// Note that usage of this type requires the `v5` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, V5Hasher};
+/// let mut hasher = V5Hasher::new(&Uuid::NAMESPACE_DNS);
+///
+/// hasher.update(b"rust-");
+/// hasher.update(b"lang.org");
+///
+/// assert_eq!(
+///     Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"),
+///     hasher.finalize(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct V5Hasher(crate::sha1::Hasher);
+
+impl V5Hasher {
+    /// Creates a hasher for names in the given namespace.
+    pub fn new(namespace: &Uuid) -> Self {
+        V5Hasher(crate::sha1::Hasher::new(namespace.as_bytes()))
+    }
+
+    /// Feeds the next chunk of the name into the hasher.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.0.update(chunk);
+    }
+
+    /// Consumes the hasher, returning a version 5 UUID for the namespace and
+    /// all of the chunks fed into it.
+    pub fn finalize(self) -> Uuid {
+        crate::Builder::from_sha1_bytes(self.0.finalize()).into_uuid()
+    }
+}
+
+impl fmt::Debug for V5Hasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("V5Hasher").finish_non_exhaustive()
+    }
+}
+
+/// A reusable context for generating many version 5 UUIDs in one namespace.
+///
+/// The namespace is hashed once when the context is created, and each call
+/// to [`generate`](#method.generate) starts from a copy of that state. This
+/// produces the same UUIDs as [`Uuid::new_v5`].
+///
+/// Note that usage of this type requires the `v5` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{Uuid, V5Context};
+/// let context = V5Context::new(&Uuid::NAMESPACE_DNS);
+///
+/// for name in ["rust-lang.org", "crates.io"] {
+///     assert_eq!(
+///         Uuid::new_v5(&Uuid::NAMESPACE_DNS, name.as_bytes()),
+///         context.generate(name.as_bytes()),
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct V5Context(crate::sha1::Hasher);
+
+impl V5Context {
+    /// Creates a context for names in the given namespace.
+    pub fn new(namespace: &Uuid) -> Self {
+        V5Context(crate::sha1::Hasher::new(namespace.as_bytes()))
+    }
+
+    /// Returns the version 5 UUID for `name` in the namespace of this
+    /// context.
+    pub fn generate(&self, name: &[u8]) -> Uuid {
+        let mut hasher = self.0.clone();
+        hasher.update(name);
+
+        crate::Builder::from_sha1_bytes(hasher.finalize()).into_uuid()
+    }
+}
+
+impl fmt::Debug for V5Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("V5Context").finish_non_exhaustive()
+    }
+}
 
 impl Uuid {
     /// Creates a UUID using a name from a namespace, based on the SHA-1 hash.
@@ -35,6 +133,31 @@ impl Uuid {
     pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
         crate::Builder::from_sha1_bytes(crate::sha1::hash(namespace.as_bytes(), name)).into_uuid()
     }
+
+    /// Checks whether this is the version 5 UUID for a name in a namespace.
+    ///
+    /// There's no way to recover the name or namespace from a version 5
+    /// UUID, but this recomputes the UUID from the expected inputs using
+    /// [`Uuid::new_v5`] and compares it.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("c66bbb60-d62e-5f17-a399-3a0bd237c503")?;
+    ///
+    /// assert!(uuid.is_v5_of(&Uuid::NAMESPACE_DNS, b"rust-lang.org"));
+    /// assert!(!uuid.is_v5_of(&Uuid::NAMESPACE_URL, b"rust-lang.org"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_v5_of(&self, namespace: &Uuid, name: &[u8]) -> bool {
+        *self == Uuid::new_v5(namespace, name)
+    }
 }
 
 #[cfg(test)]
@@ -159,4 +282,56 @@ mod tests {
             assert_eq!(Ok(uuid), u.parse());
         }
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_v5_of() {
+        for &(ns, name, u) in FIXTURE {
+            let uuid = Uuid::parse_str(u).unwrap();
+
+            assert!(uuid.is_v5_of(ns, name.as_bytes()));
+            assert!(!uuid.is_v5_of(ns, b"other"));
+            assert!(!uuid.is_v5_of(&Uuid::nil(), name.as_bytes()));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_hasher() {
+        for &(ns, name, expected) in FIXTURE {
+            let (head, tail) = name.as_bytes().split_at(name.len() / 2);
+
+            let mut hasher = V5Hasher::new(ns);
+            hasher.update(head);
+            hasher.update(&[]);
+            hasher.update(tail);
+
+            assert_eq!(hasher.finalize().hyphenated().to_string(), expected);
+        }
+
+        assert_eq!(
+            Uuid::new_v5(&Uuid::NAMESPACE_URL, b""),
+            V5Hasher::new(&Uuid::NAMESPACE_URL).finalize()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_context() {
+        for &(ns, name, expected) in FIXTURE {
+            let context = V5Context::new(ns);
+
+            assert_eq!(
+                context.generate(name.as_bytes()).hyphenated().to_string(),
+                expected
+            );
+
+            // Generating doesn't change the context
+            assert_eq!(
+                context.generate(name.as_bytes()),
+                context.generate(name.as_bytes())
+            );
+            assert_eq!(Uuid::new_v5(ns, b"other"), context.generate(b"other"));
+        }
+    }
 }
@@ -22,6 +22,45 @@ impl Uuid {
         Self::new_v6(ts, node_id)
     }
 
+    /// Create a new version 6 UUID using the current system time and a
+    /// random node ID.
+    ///
+    /// This is the same as [`Uuid::now_v6`], except the node ID is random
+    /// with its multicast bit set, as RFC4122 recommends for node IDs that
+    /// aren't a MAC address. The clock sequence comes from a context shared
+    /// by the whole process, so no state needs to be managed to generate
+    /// sortable time-based UUIDs.
+    ///
+    /// Note that usage of this method requires the `v6`, `std`, and `rng` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid = Uuid::now_v6_random_node();
+    ///
+    /// assert_eq!(Some(Version::SortMac), uuid.get_version());
+    /// assert_eq!(Some(true), uuid.is_multicast_node());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Node IDs that do not identify the host in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.5)
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn now_v6_random_node() -> Self {
+        let bytes = crate::rng::bytes();
+
+        Self::now_v6(&[
+            bytes[0] | 0x01,
+            bytes[1],
+            bytes[2],
+            bytes[3],
+            bytes[4],
+            bytes[5],
+        ])
+    }
+
     /// Create a new version 6 UUID using the given timestamp and a node ID.
     ///
     /// This is similar to version 1 UUIDs, except that it is lexicographically sortable by timestamp.
@@ -147,6 +186,19 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_now_random_node() {
+        let uuid = Uuid::now_v6_random_node();
+
+        assert_eq!(uuid.get_version(), Some(Version::SortMac));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid.is_multicast_node(), Some(true));
+
+        assert_ne!(uuid.get_node_id(), Uuid::now_v6_random_node().get_node_id());
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_new_context() {
@@ -3,7 +3,17 @@
 //! Note that you need to enable the `v7` Cargo feature
 //! in order to use this module.
 
-use crate::{std::convert::TryInto, rng, timestamp::Timestamp, Builder, Uuid};
+use crate::{
+    error::{Error, ErrorKind},
+    rng,
+    std::convert::TryInto,
+    timestamp::Timestamp,
+    Builder, Uuid,
+};
+
+/// The largest number of milliseconds that fits in the timestamp of a
+/// version 7 UUID, some time in the year 10889.
+const MAX_UNIX_TIMESTAMP_MILLIS: u64 = 0xFFFF_FFFF_FFFF;
 
 impl Uuid {
     /// Create a new version 7 UUID using the current time value and random bytes.
@@ -41,16 +51,63 @@ impl Uuid {
     /// );
     /// ```
     ///
+    /// # Overflow
+    ///
+    /// The timestamp of a version 7 UUID is 48 bits of milliseconds since the
+    /// Unix epoch, which runs out some time in the year 10889. Timestamps
+    /// after that saturate to the largest value that fits. Use
+    /// [`Uuid::try_new_v7`] to detect this instead.
+    ///
     /// # References
     ///
     /// * [Version 7 UUIDs in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-5.2)
     pub fn new_v7(ts: Timestamp) -> Self {
-        let (secs, nanos) = ts.to_unix();
-        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+        let millis = unix_timestamp_millis(ts).unwrap_or(MAX_UNIX_TIMESTAMP_MILLIS);
 
         Builder::from_unix_timestamp_millis(millis, &rng::bytes()[..10].try_into().unwrap())
             .into_uuid()
     }
+
+    /// Create a new version 7 UUID using a time value and random bytes,
+    /// failing if the time value doesn't fit.
+    ///
+    /// This is the same as [`Uuid::new_v7`], except that timestamps after
+    /// some time in the year 10889, which don't fit in the 48 bit timestamp
+    /// field, return an error instead of saturating.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    /// assert!(Uuid::try_new_v7(ts).is_ok());
+    ///
+    /// let ts = Timestamp::from_unix(NoContext, 300_000_000_000, 0);
+    /// assert!(Uuid::try_new_v7(ts).is_err());
+    /// ```
+    pub fn try_new_v7(ts: Timestamp) -> Result<Self, Error> {
+        match unix_timestamp_millis(ts) {
+            Some(millis) => Ok(Builder::from_unix_timestamp_millis(
+                millis,
+                &rng::bytes()[..10].try_into().unwrap(),
+            )
+            .into_uuid()),
+            None => Err(Error(ErrorKind::TimestampOverflow)),
+        }
+    }
+}
+
+/// The milliseconds since the Unix epoch of a timestamp, if they fit in a
+/// version 7 UUID.
+fn unix_timestamp_millis(ts: Timestamp) -> Option<u64> {
+    let (secs, nanos) = ts.to_unix();
+
+    secs.checked_mul(1000)
+        .and_then(|millis| millis.checked_add(nanos as u64 / 1_000_000))
+        .filter(|&millis| millis <= MAX_UNIX_TIMESTAMP_MILLIS)
 }
 
 #[cfg(test)]
@@ -121,4 +178,27 @@ mod tests {
 
         assert_eq!(ts.to_unix(), decoded_ts.to_unix());
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_timestamp_overflow() {
+        let max_secs = MAX_UNIX_TIMESTAMP_MILLIS / 1000;
+        let max_nanos = (MAX_UNIX_TIMESTAMP_MILLIS % 1000) as u32 * 1_000_000;
+
+        let max = Timestamp::from_unix(NoContext, max_secs, max_nanos);
+        let uuid = Uuid::try_new_v7(max).unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::SortRand));
+        assert_eq!(&[0xFF; 6], &uuid.as_bytes()[..6]);
+
+        for &secs in &[max_secs + 1, u64::MAX / 1000, u64::MAX] {
+            let ts = Timestamp::from_unix(NoContext, secs, 0);
+
+            assert_eq!(
+                Uuid::try_new_v7(ts),
+                Err(Error(ErrorKind::TimestampOverflow))
+            );
+            assert_eq!(&[0xFF; 6], &Uuid::new_v7(ts).as_bytes()[..6]);
+        }
+    }
 }